@@ -22,8 +22,15 @@ use cumulus_primitives_core::ParaId;
 use hex_literal::hex;
 use sc_service::ChainType;
 use sp_core::{crypto::UncheckedInto, sr25519};
+use sp_runtime::traits::AccountIdConversion;
 use trappist_runtime::{
-	constants::currency::EXISTENTIAL_DEPOSIT, AccountId, AuraId, Balance, SessionKeys,
+	constants::currency::EXISTENTIAL_DEPOSIT, AccountId, AssetRegistryAccountFunding,
+	AssetRegistryPalletId, AuraId, Balance, SessionKeys,
+};
+use xcm::latest::{
+	Junction::{GeneralIndex, PalletInstance, Parachain},
+	Junctions::X3,
+	MultiLocation,
 };
 
 const DEFAULT_PROTOCOL_ID: &str = "hop";
@@ -126,6 +133,17 @@ pub fn trappist_local_testnet_config() -> ChainSpec {
 	.build()
 }
 
+/// Asset Hub derivatives registered at genesis.
+fn asset_registry_genesis() -> serde_json::Value {
+	let rusd = MultiLocation::new(1, X3(Parachain(1000), PalletInstance(50), GeneralIndex(1984)));
+	serde_json::json!({
+		"assets": [
+			// id, location, is_sufficient, min_balance, name, symbol, decimals, reserve_decimals
+			(1984, rusd, true, 70_000, b"Rococo USD".to_vec(), b"RUSD".to_vec(), 6, 6),
+		],
+	})
+}
+
 /// Configure initial storage state for FRAME modules.
 pub fn testnet_genesis(
 	invulnerables: Vec<(AccountId, AuraId)>,
@@ -133,10 +151,15 @@ pub fn testnet_genesis(
 	root_key: AccountId,
 	id: ParaId,
 ) -> serde_json::Value {
-	let balances: Vec<(sp_runtime::AccountId32, Balance)> = endowed_accounts
+	let mut balances: Vec<(sp_runtime::AccountId32, Balance)> = endowed_accounts
 		.iter()
 		.map(|x| (x.clone(), 1_000_000_000_000_000_000))
 		.collect::<Vec<_>>();
+	// the registry account pays the metadata deposits of the genesis derivatives
+	balances.push((
+		AssetRegistryPalletId::get().into_account_truncating(),
+		AssetRegistryAccountFunding::get(),
+	));
 	serde_json::json!({
 		"balances": {
 			"balances": balances
@@ -164,6 +187,7 @@ pub fn testnet_genesis(
 		"polkadotXcm": {
 			"safeXcmVersion": Some(SAFE_XCM_VERSION),
 		},
+		"assetRegistry": asset_registry_genesis(),
 	})
 }
 
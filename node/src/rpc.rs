@@ -19,7 +19,7 @@
 
 use std::sync::Arc;
 
-use parachains_common::{AccountId, Balance, Block, Nonce};
+use parachains_common::{AccountId, AssetIdForTrustBackedAssets, Balance, Block, Nonce};
 use sc_client_api::AuxStore;
 pub use sc_rpc::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
@@ -55,11 +55,13 @@ where
 		+ 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_asset_registry_rpc::AssetRegistryRuntimeApi<Block, AssetIdForTrustBackedAssets>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use pallet_asset_registry_rpc::{AssetRegistry, AssetRegistryApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
@@ -70,6 +72,7 @@ where
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 	module.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
+	module.merge(AssetRegistry::new(client.clone()).into_rpc())?;
 
 	Ok(module)
 }
@@ -0,0 +1,147 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the asset registry pallet.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use xcm::VersionedMultiLocation;
+
+pub use pallet_asset_registry_runtime_api::AssetRegistryApi as AssetRegistryRuntimeApi;
+
+#[rpc(client, server)]
+pub trait AssetRegistryApi<BlockHash, AssetId> {
+	/// Location of the reserve asset registered as `asset_id`.
+	#[method(name = "assetRegistry_assetLocation")]
+	fn asset_location(
+		&self,
+		asset_id: AssetId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<VersionedMultiLocation>>;
+
+	/// Local asset id of the reserve asset at `location`.
+	#[method(name = "assetRegistry_assetId")]
+	fn asset_id(
+		&self,
+		location: VersionedMultiLocation,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AssetId>>;
+
+	/// All registered reserve assets.
+	#[method(name = "assetRegistry_registeredAssets")]
+	fn registered_assets(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AssetId, VersionedMultiLocation)>>;
+
+	/// Registered reserve assets whose reserve is the chain at `reserve`.
+	#[method(name = "assetRegistry_assetsByReserve")]
+	fn assets_by_reserve(
+		&self,
+		reserve: VersionedMultiLocation,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AssetId, VersionedMultiLocation)>>;
+}
+
+/// Provides RPC methods to query the asset registry.
+pub struct AssetRegistry<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> AssetRegistry<C, Block> {
+	/// Creates a new instance of the asset registry RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(error: sp_api::ApiError) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the asset registry.",
+		Some(error.to_string()),
+	))
+	.into()
+}
+
+impl<C, Block, AssetId> AssetRegistryApiServer<<Block as BlockT>::Hash, AssetId>
+	for AssetRegistry<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AssetRegistryRuntimeApi<Block, AssetId>,
+	AssetId: Codec,
+{
+	fn asset_location(
+		&self,
+		asset_id: AssetId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<VersionedMultiLocation>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().asset_location(at, asset_id).map_err(runtime_error)
+	}
+
+	fn asset_id(
+		&self,
+		location: VersionedMultiLocation,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<AssetId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().asset_id(at, location).map_err(runtime_error)
+	}
+
+	fn registered_assets(
+		&self,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(AssetId, VersionedMultiLocation)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().registered_assets(at).map_err(runtime_error)
+	}
+
+	fn assets_by_reserve(
+		&self,
+		reserve: VersionedMultiLocation,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(AssetId, VersionedMultiLocation)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().assets_by_reserve(at, reserve).map_err(runtime_error)
+	}
+}
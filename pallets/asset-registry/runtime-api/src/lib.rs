@@ -0,0 +1,47 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the asset registry pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+use xcm::VersionedMultiLocation;
+
+sp_api::decl_runtime_apis! {
+	/// API to query the reserve assets registered in `pallet-asset-registry`.
+	///
+	/// Locations are returned as `VersionedMultiLocation` so the encoding stays stable across XCM
+	/// version upgrades.
+	pub trait AssetRegistryApi<AssetId>
+	where
+		AssetId: Codec,
+	{
+		/// Location of the reserve asset registered as `asset_id`.
+		fn asset_location(asset_id: AssetId) -> Option<VersionedMultiLocation>;
+
+		/// Local asset id of the reserve asset at `location`.
+		fn asset_id(location: VersionedMultiLocation) -> Option<AssetId>;
+
+		/// All registered reserve assets.
+		fn registered_assets() -> Vec<(AssetId, VersionedMultiLocation)>;
+
+		/// Registered reserve assets whose reserve is the chain at `reserve`.
+		fn assets_by_reserve(reserve: VersionedMultiLocation) -> Vec<(AssetId, VersionedMultiLocation)>;
+	}
+}
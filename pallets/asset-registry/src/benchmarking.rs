@@ -19,13 +19,59 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as AssetRegistry;
-use frame_benchmarking::benchmarks;
-use frame_support::assert_ok;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{fungible, Get},
+};
 use frame_system::RawOrigin;
+use sp_runtime::{
+	traits::{Bounded, Saturating},
+	BoundedVec,
+};
+use sp_std::vec;
 use xcm::opaque::latest::{
 	Junction::{GeneralIndex, PalletInstance, Parachain},
 	Junctions, MultiLocation,
 };
+use xcm_primitives::{AssetStatus, AssetTrust};
+
+fn asset_multi_location() -> MultiLocation {
+	MultiLocation {
+		parents: 1,
+		interior: Junctions::X3(
+			Parachain(Default::default()),
+			PalletInstance(Default::default()),
+			GeneralIndex(Default::default()),
+		),
+	}
+}
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	let balance = T::RegistrationDeposit::get()
+		.saturating_mul(10u32.into())
+		.saturating_add(<T::Currency as fungible::Inspect<_>>::minimum_balance());
+	<T::Currency as fungible::Mutate<_>>::set_balance(&caller, balance);
+	caller
+}
+
+fn max_metadata<T: Config>() -> AssetMetadataOf<T> {
+	let string: BoundedVec<u8, T::StringLimit> =
+		vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+	AssetMetadata { name: string.clone(), symbol: string, decimals: 12, reserve_decimals: 12 }
+}
+
+fn request_registration<T: Config>() -> AssetIdOf<T> {
+	let caller = funded_caller::<T>();
+	assert_ok!(AssetRegistry::<T>::register_reserve_asset_with_deposit(
+		RawOrigin::Signed(caller).into(),
+		asset_multi_location(),
+		max_metadata::<T>(),
+		1u32.into(),
+	));
+	PendingMultiLocationId::<T>::get(asset_multi_location()).unwrap()
+}
 
 benchmarks! {
 	register_reserve_asset {
@@ -34,7 +80,7 @@ benchmarks! {
 			parents: 1,
 			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(Default::default()), GeneralIndex(Default::default()))
 		};
-	}: _(RawOrigin::Root, asset_id.clone(), asset_multi_location)
+	}: _(RawOrigin::Root, asset_id.clone(), asset_multi_location, None)
 	verify {
 		assert_eq!(AssetIdMultiLocation::<T>::get(asset_id), Some(asset_multi_location));
 	}
@@ -45,12 +91,171 @@ benchmarks! {
 			parents: 1,
 			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(Default::default()), GeneralIndex(Default::default()))
 		};
-		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location));
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location, None));
 		assert!(AssetIdMultiLocation::<T>::contains_key(asset_id.clone()));
 	}: _(RawOrigin::Root, asset_id.clone())
 	verify {
 		assert_eq!(AssetIdMultiLocation::<T>::get(asset_id), None);
 	}
 
+	update_reserve_asset_location {
+		let asset_id = T::BenchmarkHelper::get_registered_asset();
+		let asset_multi_location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(Default::default()), GeneralIndex(Default::default()))
+		};
+		let new_asset_multi_location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(1), GeneralIndex(Default::default()))
+		};
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location, None));
+		let alias_expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+	}: _(RawOrigin::Root, asset_id.clone(), new_asset_multi_location, Some(alias_expiry))
+	verify {
+		assert_eq!(AssetIdMultiLocation::<T>::get(asset_id.clone()), Some(new_asset_multi_location));
+		assert_eq!(AssetAliasMultiLocationId::<T>::get(asset_multi_location), Some((asset_id, alias_expiry)));
+	}
+
+	remove_reserve_asset_alias {
+		let asset_id = T::BenchmarkHelper::get_registered_asset();
+		let asset_multi_location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(Default::default()), GeneralIndex(Default::default()))
+		};
+		let new_asset_multi_location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(1), GeneralIndex(Default::default()))
+		};
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location, None));
+		let alias_expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		assert_ok!(AssetRegistry::<T>::update_reserve_asset_location(RawOrigin::Root.into(), asset_id, new_asset_multi_location, Some(alias_expiry)));
+	}: _(RawOrigin::Root, asset_multi_location)
+	verify {
+		assert!(!AssetAliasMultiLocationId::<T>::contains_key(asset_multi_location));
+	}
+
+	set_asset_trust {
+		let asset_id = T::BenchmarkHelper::get_registered_asset();
+		let asset_multi_location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(Default::default()), GeneralIndex(Default::default()))
+		};
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location, None));
+		let trust = AssetTrust::Bridged(MultiLocation { parents: 1, interior: Junctions::X1(Parachain(Default::default())) });
+	}: _(RawOrigin::Root, asset_id.clone(), trust)
+	verify {
+		assert_eq!(AssetIdTrust::<T>::get(asset_id), trust);
+	}
+
+	register_reserve_asset_with_deposit {
+		let caller = funded_caller::<T>();
+		let asset_multi_location = asset_multi_location();
+	}: _(RawOrigin::Signed(caller), asset_multi_location, max_metadata::<T>(), 1u32.into())
+	verify {
+		assert!(PendingMultiLocationId::<T>::contains_key(asset_multi_location));
+	}
+
+	approve_reserve_asset_registration {
+		let asset_id = request_registration::<T>();
+		// the registry account pays the metadata deposit
+		<T::Currency as fungible::Mutate<_>>::set_balance(
+			&AssetRegistry::<T>::account_id(),
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+	}: _(RawOrigin::Root, asset_id.clone())
+	verify {
+		assert_eq!(AssetIdMultiLocation::<T>::get(asset_id), Some(asset_multi_location()));
+	}
+
+	reject_reserve_asset_registration {
+		let asset_id = request_registration::<T>();
+	}: _(RawOrigin::Root, asset_id.clone())
+	verify {
+		assert!(!PendingRegistrations::<T>::contains_key(asset_id));
+	}
+
+	refund_reserve_asset_registration {
+		let asset_id = request_registration::<T>();
+	}: _(RawOrigin::Root, asset_id.clone())
+	verify {
+		assert!(!PendingRegistrations::<T>::contains_key(asset_id));
+	}
+
+	create_and_register_reserve_asset {
+		// the registry account pays the metadata deposit
+		<T::Currency as fungible::Mutate<_>>::set_balance(
+			&AssetRegistry::<T>::account_id(),
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		let asset_multi_location = asset_multi_location();
+	}: _(RawOrigin::Root, asset_multi_location, 1u32.into(), true, max_metadata::<T>())
+	verify {
+		assert!(AssetMultiLocationId::<T>::contains_key(asset_multi_location));
+	}
+
+	set_asset_units_per_second {
+		let asset_id = T::BenchmarkHelper::get_registered_asset();
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location(), None));
+	}: _(RawOrigin::Root, asset_id.clone(), Some(u128::MAX))
+	verify {
+		assert_eq!(AssetIdUnitsPerSecond::<T>::get(asset_id), Some(u128::MAX));
+	}
+
+	set_asset_status {
+		let asset_id = T::BenchmarkHelper::get_registered_asset();
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location(), None));
+	}: _(RawOrigin::Root, asset_id.clone(), AssetStatus::Frozen)
+	verify {
+		assert_eq!(AssetIdStatus::<T>::get(asset_id), AssetStatus::Frozen);
+	}
+
+	set_asset_flow_limits {
+		let asset_id = T::BenchmarkHelper::get_registered_asset();
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location(), None));
+		// removing existing limits also clears the flow
+		assert_ok!(AssetRegistry::<T>::set_asset_flow_limits(
+			RawOrigin::Root.into(),
+			asset_id.clone(),
+			Some(FlowLimits { inbound: Some(u128::MAX), outbound: Some(u128::MAX), quarantine: true }),
+		));
+	}: _(RawOrigin::Root, asset_id.clone(), None)
+	verify {
+		assert!(!AssetIdFlowLimits::<T>::contains_key(asset_id));
+	}
+
+	remove_deprecated_reserve_asset {
+		let asset_id = T::BenchmarkHelper::get_registered_asset();
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location(), None));
+		// the supply of the deprecated asset is already gone
+		AssetIdStatus::<T>::insert(asset_id.clone(), AssetStatus::Deprecated);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), asset_id.clone())
+	verify {
+		assert_eq!(AssetIdMultiLocation::<T>::get(asset_id), None);
+	}
+
+	force_unregister_reserve_asset {
+		let asset_id = T::BenchmarkHelper::get_registered_asset();
+		assert_ok!(AssetRegistry::<T>::register_reserve_asset(RawOrigin::Root.into(), asset_id.clone(), asset_multi_location(), None));
+	}: _(RawOrigin::Root, asset_id.clone())
+	verify {
+		assert_eq!(AssetIdMultiLocation::<T>::get(asset_id), None);
+	}
+
+	register_reserve_collection {
+		let collection_id = T::BenchmarkHelper::get_registered_collection();
+	}: _(RawOrigin::Root, collection_id.clone(), asset_multi_location())
+	verify {
+		assert_eq!(CollectionIdMultiLocation::<T>::get(collection_id), Some(asset_multi_location()));
+	}
+
+	unregister_reserve_collection {
+		let collection_id = T::BenchmarkHelper::get_registered_collection();
+		assert_ok!(AssetRegistry::<T>::register_reserve_collection(RawOrigin::Root.into(), collection_id.clone(), asset_multi_location()));
+	}: _(RawOrigin::Root, collection_id.clone())
+	verify {
+		assert_eq!(CollectionIdMultiLocation::<T>::get(collection_id), None);
+	}
+
 	impl_benchmark_test_suite!(AssetRegistry, crate::mock::new_test_ext(), crate::mock::Test);
 }
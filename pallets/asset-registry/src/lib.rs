@@ -32,37 +32,200 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+use xcm::latest::{
+	AssetId as XcmAssetId,
+	Junction::{
+		AccountId32, AccountKey20, GeneralIndex, GeneralKey, GlobalConsensus, PalletInstance,
+		Parachain,
+	},
+	MultiLocation,
+};
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{pallet_prelude::*, traits::tokens::fungibles::Inspect};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			tokens::{
+				fungible,
+				fungibles::{
+					metadata::{Inspect as InspectMetadata, Mutate as MutateMetadata},
+					Create, Destroy, Inspect,
+				},
+				nonfungibles, Fortitude, Precision,
+			},
+			Contains, Incrementable,
+		},
+		PalletId,
+	};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, BadOrigin, MaybeSerializeDeserialize, Saturating, Zero},
+		SaturatedConversion,
+	};
+	use sp_std::vec::Vec;
+	use xcm_primitives::{
+		reserve_chain, AssetDecimals, AssetFlowLimiter, AssetMultiLocationGetter, AssetStatus,
+		AssetStatusGetter, AssetTrust, AssetTrustGetter, CollectionMultiLocationGetter,
+		RecordLostAsset, UnitsPerSecondGetter,
+	};
 
-	use xcm::latest::{
-		Junction::{AccountId32, AccountKey20, GeneralIndex, PalletInstance, Parachain},
-		MultiLocation,
+	use xcm::{
+		latest::{Error as XcmError, MultiLocation},
+		VersionedMultiLocation,
 	};
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
-	type AssetIdOf<T> =
-		<<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+	pub type AssetIdOf<T> = <T as Config>::AssetId;
+
+	pub type AssetBalanceOf<T> =
+		<<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	pub type PendingRegistrationOf<T> = PendingRegistration<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		AssetMetadataOf<T>,
+	>;
+
+	pub type AssetMetadataOf<T> = AssetMetadata<BoundedVec<u8, <T as Config>::StringLimit>>;
+
+	/// Metadata of a registered reserve asset.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct AssetMetadata<BoundedString> {
+		/// The user friendly name of the asset.
+		pub name: BoundedString,
+		/// The ticker symbol of the asset.
+		pub symbol: BoundedString,
+		/// Decimals of the local derivative.
+		pub decimals: u8,
+		/// Decimals of the asset on its reserve chain.
+		pub reserve_decimals: u8,
+	}
+
+	/// A reserve asset registration awaiting approval.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PendingRegistration<AccountId, Balance, Metadata> {
+		/// The account holding the registration deposit.
+		pub depositor: AccountId,
+		/// The amount held from `depositor`.
+		pub deposit: Balance,
+		/// The location the asset will be registered at once approved.
+		pub asset_multi_location: MultiLocation,
+		/// The metadata the asset will be registered with once approved.
+		pub metadata: Metadata,
+	}
+
+	pub type AssetFlowOf<T> = AssetFlow<BlockNumberFor<T>>;
+
+	/// Caps on the amount of an asset crossing the chain boundary per `FlowLimitWindow`,
+	/// expressed in the asset's units on its reserve chain.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct FlowLimits {
+		/// Maximum amount deposited from other chains, if any.
+		pub inbound: Option<u128>,
+		/// Maximum amount withdrawn to other chains, if any.
+		pub outbound: Option<u128>,
+		/// Whether exceeding a limit quarantines the asset.
+		pub quarantine: bool,
+	}
+
+	/// Amounts of an asset which crossed the chain boundary in the current window.
+	#[derive(
+		Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct AssetFlow<BlockNumber> {
+		/// The block the current window started at.
+		pub window_start: BlockNumber,
+		pub inbound: u128,
+		pub outbound: u128,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum FlowDirection {
+		Inbound,
+		Outbound,
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds are held for a pending reserve asset registration.
+		RegistrationDeposit,
+	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	pub trait BenchmarkHelper<AssetId> {
+	pub trait BenchmarkHelper<AssetId, CollectionId> {
+		/// An asset id which exists in the `Assets` pallet without any supply.
 		fn get_registered_asset() -> AssetId;
+		/// A collection id which exists in `Collections`.
+		fn get_registered_collection() -> CollectionId;
 	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		type ReserveAssetModifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
-		type Assets: Inspect<Self::AccountId>;
+		/// Origin allowed to register, update and unregister reserve assets.
+		///
+		/// A `Some` success value restricts the origin to asset locations under that prefix,
+		/// e.g. a sibling reserve chain managing its own assets. `None` means no restriction.
+		type ReserveAssetModifierOrigin: EnsureOrigin<
+			Self::RuntimeOrigin,
+			Success = Option<MultiLocation>,
+		>;
+		/// Identifier of the local derivatives.
+		type AssetId: Member
+			+ Parameter
+			+ MaxEncodedLen
+			+ MaybeSerializeDeserialize
+			+ Ord
+			+ Incrementable;
+		type Assets: Inspect<Self::AccountId, AssetId = Self::AssetId>
+			+ InspectMetadata<Self::AccountId>
+			+ MutateMetadata<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ Destroy<Self::AccountId>;
+		/// The first and last asset id derivatives created by the registry are given.
+		///
+		/// Assets created outside of the registry should not use ids in this range.
+		#[pallet::constant]
+		type DerivativeAssetIdRange: Get<(Self::AssetId, Self::AssetId)>;
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency the registration deposit is held in.
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The deposit held from accounts requesting a reserve asset registration.
+		#[pallet::constant]
+		type RegistrationDeposit: Get<BalanceOf<Self>>;
+		/// The pallet id, used to derive the account owning the derivatives created by the
+		/// pallet. The account pays their metadata deposits in `Assets`, so the runtime must keep
+		/// it funded.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// The maximum length of the name and symbol stored in the asset metadata.
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+		/// The locations assets may be registered at, e.g.
+		/// `(StandardAssetLocations, BridgedAssetLocations<StandardAssetLocations>)`.
+		type AssetLocationFilter: Contains<MultiLocation>;
+		/// The number of blocks over which flow limits are accounted.
+		#[pallet::constant]
+		type FlowLimitWindow: Get<BlockNumberFor<Self>>;
+		/// Identifier of the local collections holding derivatives of non-fungible assets.
+		type CollectionId: Member + Parameter + MaxEncodedLen + MaybeSerializeDeserialize;
+		/// The non-fungibles implementation the derivative collections live in, e.g. `Uniques`.
+		type Collections: nonfungibles::Inspect<Self::AccountId, CollectionId = Self::CollectionId>;
 		type WeightInfo: WeightInfo;
 		/// Helper trait for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
-		type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self>>;
+		type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self>, Self::CollectionId>;
 	}
 
 	#[pallet::storage]
@@ -73,11 +236,150 @@ pub mod pallet {
 	pub type AssetMultiLocationId<T: Config> =
 		StorageMap<_, Blake2_128Concat, MultiLocation, AssetIdOf<T>>;
 
+	/// Previous locations of registered assets, resolving to the asset until the given block.
+	#[pallet::storage]
+	pub type AssetAliasMultiLocationId<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, (AssetIdOf<T>, BlockNumberFor<T>)>;
+
+	/// The alias of each asset in `AssetAliasMultiLocationId`, so it is removed along with the
+	/// asset.
+	#[pallet::storage]
+	pub type AssetIdAliasMultiLocation<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, MultiLocation>;
+
+	/// The asset id given to the next derivative created by the registry.
+	#[pallet::storage]
+	pub type NextDerivativeAssetId<T: Config> = StorageValue<_, AssetIdOf<T>>;
+
+	/// Registrations requested with a deposit, awaiting approval.
+	#[pallet::storage]
+	pub type PendingRegistrations<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, PendingRegistrationOf<T>>;
+
+	/// The pending registration of each location in `PendingRegistrations`.
+	#[pallet::storage]
+	pub type PendingMultiLocationId<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, AssetIdOf<T>>;
+
+	#[pallet::storage]
+	pub type AssetIdMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, AssetMetadataOf<T>>;
+
+	/// How far registered assets are trusted when they arrive over XCM.
+	#[pallet::storage]
+	pub type AssetIdTrust<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, AssetTrust, ValueQuery>;
+
+	/// Units of a registered asset charged per second of execution weight, for assets accepted
+	/// as XCM fee payment.
+	#[pallet::storage]
+	pub type AssetIdUnitsPerSecond<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, u128>;
+
+	/// Registered assets which are frozen, quarantined or deprecated. Missing assets are active.
+	#[pallet::storage]
+	pub type AssetIdStatus<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, AssetStatus, ValueQuery>;
+
+	#[pallet::storage]
+	pub type AssetIdFlowLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, FlowLimits>;
+
+	/// Flow of assets with limits in the current `FlowLimitWindow`.
+	#[pallet::storage]
+	pub type AssetIdFlow<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, AssetFlowOf<T>, ValueQuery>;
+
+	/// Local collections holding the derivatives of the non-fungible collection at a location.
+	#[pallet::storage]
+	pub type CollectionIdMultiLocation<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, MultiLocation>;
+
+	#[pallet::storage]
+	pub type CollectionMultiLocationId<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, T::CollectionId>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		ReserveAssetRegistered { asset_id: AssetIdOf<T>, asset_multi_location: MultiLocation },
-		ReserveAssetUnregistered { asset_id: AssetIdOf<T>, asset_multi_location: MultiLocation },
+		ReserveAssetRegistered {
+			asset_id: AssetIdOf<T>,
+			asset_multi_location: MultiLocation,
+			metadata: AssetMetadataOf<T>,
+		},
+		ReserveAssetUnregistered {
+			asset_id: AssetIdOf<T>,
+			asset_multi_location: MultiLocation,
+		},
+		/// The asset still has `supply` outstanding, so it was deprecated instead of being
+		/// unregistered. It is removed by `remove_deprecated_reserve_asset` once the supply is
+		/// gone.
+		ReserveAssetDeprecated {
+			asset_id: AssetIdOf<T>,
+			asset_multi_location: MultiLocation,
+			supply: AssetBalanceOf<T>,
+		},
+		ReserveAssetLocationUpdated {
+			asset_id: AssetIdOf<T>,
+			old_asset_multi_location: MultiLocation,
+			new_asset_multi_location: MultiLocation,
+			alias_expiry: Option<BlockNumberFor<T>>,
+		},
+		ReserveAssetAliasRemoved {
+			asset_id: AssetIdOf<T>,
+			alias_multi_location: MultiLocation,
+		},
+		AssetTrustUpdated {
+			asset_id: AssetIdOf<T>,
+			trust: AssetTrust,
+		},
+		AssetUnitsPerSecondUpdated {
+			asset_id: AssetIdOf<T>,
+			units_per_second: Option<u128>,
+		},
+		AssetStatusUpdated {
+			asset_id: AssetIdOf<T>,
+			status: AssetStatus,
+		},
+		AssetFlowLimitsUpdated {
+			asset_id: AssetIdOf<T>,
+			limits: Option<FlowLimits>,
+		},
+		/// A transfer of `amount` was refused because it exceeds the flow limit of the asset.
+		AssetFlowLimitExceeded {
+			asset_id: AssetIdOf<T>,
+			direction: FlowDirection,
+			amount: u128,
+		},
+		ReserveAssetRegistrationRequested {
+			asset_id: AssetIdOf<T>,
+			asset_multi_location: MultiLocation,
+			depositor: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+		ReserveAssetRegistrationRejected {
+			asset_id: AssetIdOf<T>,
+			depositor: T::AccountId,
+			slashed: BalanceOf<T>,
+		},
+		ReserveAssetRegistrationRefunded {
+			asset_id: AssetIdOf<T>,
+			depositor: T::AccountId,
+			refunded: BalanceOf<T>,
+		},
+		ReserveCollectionRegistered {
+			collection_id: T::CollectionId,
+			collection_multi_location: MultiLocation,
+		},
+		ReserveCollectionUnregistered {
+			collection_id: T::CollectionId,
+			collection_multi_location: MultiLocation,
+		},
+		/// `amount` of an asset left in holding by a message from `origin` was lost.
+		AssetLost {
+			origin: MultiLocation,
+			asset_id: XcmAssetId,
+			amount: u128,
+		},
 	}
 
 	#[pallet::error]
@@ -90,18 +392,132 @@ pub mod pallet {
 		AssetIsNotRegistered,
 		/// Invalid MultiLocation
 		WrongMultiLocation,
+		/// The asset name or symbol is longer than `StringLimit`
+		BadMetadata,
+		/// The local decimals of the metadata are not those of the asset in `Assets`
+		DecimalsMismatch,
+		/// The MultiLocation is already registered
+		LocationAlreadyRegistered,
+		/// The MultiLocation is not an alias of a registered asset
+		AliasNotFound,
+		/// The alias expiry is not in the future
+		InvalidAliasExpiry,
+		/// The Asset ID has a pending registration
+		AssetIsPending,
+		/// The Asset ID has no pending registration
+		RegistrationNotPending,
+		/// The MultiLocation already has a pending registration
+		LocationIsPending,
+		/// All asset ids in `DerivativeAssetIdRange` are in use
+		NoDerivativeAssetIdAvailable,
+		/// The asset is already deprecated
+		AssetIsDeprecated,
+		/// The asset is not deprecated
+		AssetIsNotDeprecated,
+		/// The asset still has outstanding supply
+		AssetHasSupply,
+		/// The Collection ID does not exist in `Collections`
+		CollectionDoesNotExist,
+		/// The Collection ID is already registered
+		CollectionAlreadyRegistered,
+		/// The Collection ID is not registered
+		CollectionIsNotRegistered,
+		/// The Asset ID is not in `DerivativeAssetIdRange`
+		NotDerivativeAssetId,
+		/// The asset is frozen or quarantined
+		AssetIsNotActive,
+	}
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Derivatives to create in `Assets` and register, owned by the pallet account.
+		///
+		/// The pallet account pays the metadata deposits, so it must be endowed at genesis.
+		pub assets: Vec<(
+			// id, location, is_sufficient, min_balance, name, symbol, decimals, reserve_decimals
+			AssetIdOf<T>,
+			MultiLocation,
+			bool,
+			u128,
+			Vec<u8>,
+			Vec<u8>,
+			u8,
+			u8,
+		)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let owner = Pallet::<T>::account_id();
+			for (
+				asset_id,
+				location,
+				is_sufficient,
+				min_balance,
+				name,
+				symbol,
+				decimals,
+				reserve_decimals,
+			) in &self.assets
+			{
+				assert!(
+					!AssetMultiLocationId::<T>::contains_key(location),
+					"Location registered twice in genesis."
+				);
+				let metadata = AssetMetadata {
+					name: name.clone().try_into().expect("Asset name exceeds StringLimit"),
+					symbol: symbol.clone().try_into().expect("Asset symbol exceeds StringLimit"),
+					decimals: *decimals,
+					reserve_decimals: *reserve_decimals,
+				};
+
+				T::Assets::create(
+					asset_id.clone(),
+					owner.clone(),
+					*is_sufficient,
+					(*min_balance).saturated_into(),
+				)
+				.expect("Genesis asset cannot be created");
+				T::Assets::set(asset_id.clone(), &owner, name.clone(), symbol.clone(), *decimals)
+					.expect("Genesis asset metadata cannot be set, is the pallet account endowed?");
+
+				AssetIdMultiLocation::<T>::insert(asset_id.clone(), location);
+				AssetMultiLocationId::<T>::insert(location, asset_id.clone());
+				AssetIdMetadata::<T>::insert(asset_id.clone(), metadata);
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Register `asset_id` as the local derivative of the reserve asset at
+		/// `asset_multi_location`.
+		///
+		/// When `metadata` is not provided it is derived from the local `Assets` pallet, assuming
+		/// the reserve uses the same number of decimals as the derivative. When it is, its local
+		/// decimals must be those of the derivative in `Assets`.
+		///
+		/// Origins restricted to a prefix may only register ids in `DerivativeAssetIdRange`
+		/// without any supply, so they cannot take over assets already in use on this chain.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_reserve_asset())]
 		pub fn register_reserve_asset(
 			origin: OriginFor<T>,
 			asset_id: AssetIdOf<T>,
 			asset_multi_location: MultiLocation,
+			metadata: Option<AssetMetadataOf<T>>,
 		) -> DispatchResult {
-			T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
 
 			// verify asset exists on pallet-assets
 			ensure!(T::Assets::asset_exists(asset_id.clone()), Error::<T>::AssetDoesNotExist);
@@ -112,73 +528,1018 @@ pub mod pallet {
 				Error::<T>::AssetAlreadyRegistered
 			);
 
-			// verify MultiLocation is valid
+			// verify asset is not awaiting approval
+			ensure!(
+				!PendingRegistrations::<T>::contains_key(asset_id.clone()),
+				Error::<T>::AssetIsPending
+			);
+
+			// verify MultiLocation is valid and not in use
 			ensure!(
-				Self::valid_asset_location(&asset_multi_location),
+				Self::valid_asset_location(&asset_multi_location, prefix.as_ref()),
 				Error::<T>::WrongMultiLocation
 			);
+			Self::ensure_location_unused(&asset_multi_location)?;
 
-			// register asset_id => asset_multi_location
-			AssetIdMultiLocation::<T>::insert(asset_id.clone(), asset_multi_location);
-			// register asset_multi_location => asset_id
-			AssetMultiLocationId::<T>::insert(asset_multi_location, asset_id.clone());
+			if prefix.is_some() {
+				ensure!(Self::is_derivative_asset_id(&asset_id), Error::<T>::NotDerivativeAssetId);
+				ensure!(
+					T::Assets::total_issuance(asset_id.clone()).is_zero(),
+					Error::<T>::AssetHasSupply
+				);
+			}
 
-			Self::deposit_event(Event::ReserveAssetRegistered { asset_id, asset_multi_location });
+			let metadata = match metadata {
+				Some(metadata) => {
+					// transferred amounts are scaled to the decimals of the derivative
+					ensure!(
+						metadata.decimals == T::Assets::decimals(asset_id.clone()),
+						Error::<T>::DecimalsMismatch
+					);
+					metadata
+				},
+				None => Self::local_metadata(asset_id.clone())?,
+			};
+
+			Self::do_register(asset_id, asset_multi_location, metadata);
 			Ok(())
 		}
 
+		/// Unregister `asset_id`, or deprecate it while derivatives of it are still held.
+		///
+		/// Deprecated assets can no longer be deposited but holders may still withdraw them. They
+		/// are removed by `remove_deprecated_reserve_asset` once their supply reaches zero.
+		///
+		/// Origins restricted to a prefix can not unregister frozen or quarantined assets.
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::unregister_reserve_asset())]
 		pub fn unregister_reserve_asset(
 			origin: OriginFor<T>,
 			asset_id: AssetIdOf<T>,
 		) -> DispatchResult {
-			T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+
+			let asset_multi_location = AssetIdMultiLocation::<T>::get(asset_id.clone())
+				.ok_or(Error::<T>::AssetIsNotRegistered)?;
+			ensure!(Self::within_prefix(&asset_multi_location, prefix.as_ref()), BadOrigin);
+
+			let status = AssetIdStatus::<T>::get(asset_id.clone());
+			// frozen and quarantined assets are released by governance only
+			ensure!(prefix.is_none() || status.is_withdrawable(), Error::<T>::AssetIsNotActive);
+
+			let supply = T::Assets::total_issuance(asset_id.clone());
+			if supply.is_zero() {
+				AssetIdMultiLocation::<T>::remove(asset_id.clone());
+				Self::do_unregister(asset_id, asset_multi_location);
+				return Ok(());
+			}
+
+			ensure!(status != AssetStatus::Deprecated, Error::<T>::AssetIsDeprecated);
+			AssetIdStatus::<T>::insert(asset_id.clone(), AssetStatus::Deprecated);
+
+			Self::deposit_event(Event::ReserveAssetDeprecated {
+				asset_id,
+				asset_multi_location,
+				supply,
+			});
+			Ok(())
+		}
+
+		/// Re-point a registered asset to `new_asset_multi_location` in a single step.
+		///
+		/// If `alias_expiry` is set, the previous location keeps resolving to `asset_id` until
+		/// that block, so in-flight transfers are not rejected while the reserve migrates. Any
+		/// earlier alias of the asset is removed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_reserve_asset_location())]
+		pub fn update_reserve_asset_location(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			new_asset_multi_location: MultiLocation,
+			alias_expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+
+			// verify MultiLocation is valid
+			ensure!(
+				Self::valid_asset_location(&new_asset_multi_location, prefix.as_ref()),
+				Error::<T>::WrongMultiLocation
+			);
+
+			// verify new MultiLocation is not in use
+			Self::ensure_location_unused(&new_asset_multi_location)?;
+
+			if let Some(expiry) = alias_expiry {
+				ensure!(
+					expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidAliasExpiry
+				);
+			}
+
+			// swap asset_id => asset_multi_location, while getting the old value
+			let old_asset_multi_location = AssetIdMultiLocation::<T>::try_mutate(
+				asset_id.clone(),
+				|maybe_location| -> Result<MultiLocation, DispatchError> {
+					let location =
+						maybe_location.as_mut().ok_or(Error::<T>::AssetIsNotRegistered)?;
+					ensure!(Self::within_prefix(location, prefix.as_ref()), BadOrigin);
+					Ok(sp_std::mem::replace(location, new_asset_multi_location))
+				},
+			)?;
+			// swap asset_multi_location => asset_id
+			AssetMultiLocationId::<T>::remove(old_asset_multi_location);
+			AssetMultiLocationId::<T>::insert(new_asset_multi_location, asset_id.clone());
+
+			// an alias only forwards to the location which replaced it
+			if let Some(previous_alias) = AssetIdAliasMultiLocation::<T>::take(asset_id.clone()) {
+				AssetAliasMultiLocationId::<T>::remove(previous_alias);
+			}
+			// keep resolving the old location until the alias expires
+			if let Some(expiry) = alias_expiry {
+				AssetAliasMultiLocationId::<T>::insert(
+					old_asset_multi_location,
+					(asset_id.clone(), expiry),
+				);
+				AssetIdAliasMultiLocation::<T>::insert(asset_id.clone(), old_asset_multi_location);
+			}
+
+			Self::deposit_event(Event::ReserveAssetLocationUpdated {
+				asset_id,
+				old_asset_multi_location,
+				new_asset_multi_location,
+				alias_expiry,
+			});
+			Ok(())
+		}
+
+		/// Remove an alias location.
+		///
+		/// Before the alias expires only `ReserveAssetModifierOrigin` may remove it. Expired
+		/// aliases no longer resolve and can be cleaned up by anyone.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_reserve_asset_alias())]
+		pub fn remove_reserve_asset_alias(
+			origin: OriginFor<T>,
+			alias_multi_location: MultiLocation,
+		) -> DispatchResult {
+			let (asset_id, expiry) = AssetAliasMultiLocationId::<T>::get(alias_multi_location)
+				.ok_or(Error::<T>::AliasNotFound)?;
+
+			if expiry > frame_system::Pallet::<T>::block_number() {
+				let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+				ensure!(Self::within_prefix(&alias_multi_location, prefix.as_ref()), BadOrigin);
+			} else {
+				frame_system::ensure_signed_or_root(origin)?;
+			}
+
+			AssetAliasMultiLocationId::<T>::remove(alias_multi_location);
+			AssetIdAliasMultiLocation::<T>::remove(asset_id.clone());
+
+			Self::deposit_event(Event::ReserveAssetAliasRemoved { asset_id, alias_multi_location });
+			Ok(())
+		}
+
+		/// Set whether a registered asset is accepted as a reserve transfer, a teleport or a
+		/// bridged asset. Newly registered assets are trusted as `Reserve`.
+		///
+		/// Only an unrestricted `ReserveAssetModifierOrigin` may change the trust of an asset.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_asset_trust())]
+		pub fn set_asset_trust(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			trust: AssetTrust,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+			ensure!(prefix.is_none(), BadOrigin);
+
+			ensure!(
+				AssetIdMultiLocation::<T>::contains_key(asset_id.clone()),
+				Error::<T>::AssetIsNotRegistered
+			);
+
+			AssetIdTrust::<T>::insert(asset_id.clone(), trust);
+
+			Self::deposit_event(Event::AssetTrustUpdated { asset_id, trust });
+			Ok(())
+		}
+
+		/// Request the registration of a new reserve asset, holding `RegistrationDeposit` from
+		/// the caller.
+		///
+		/// The local derivative is given the next free id in `DerivativeAssetIdRange` and created
+		/// in the `Assets` pallet, owned by the pallet account. Until `ReserveAssetModifierOrigin`
+		/// approves the request, the location is not registered and transfers of the asset are
+		/// not accepted.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_reserve_asset_with_deposit())]
+		pub fn register_reserve_asset_with_deposit(
+			origin: OriginFor<T>,
+			asset_multi_location: MultiLocation,
+			metadata: AssetMetadataOf<T>,
+			min_balance: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			let depositor = ensure_signed(origin)?;
+
+			// verify MultiLocation is valid, not in use and not requested already
+			ensure!(
+				Self::valid_asset_location(&asset_multi_location, None),
+				Error::<T>::WrongMultiLocation
+			);
+			Self::ensure_location_unused(&asset_multi_location)?;
+
+			let deposit = T::RegistrationDeposit::get();
+			<T::Currency as fungible::MutateHold<_>>::hold(
+				&HoldReason::RegistrationDeposit.into(),
+				&depositor,
+				deposit,
+			)?;
+
+			let asset_id = Self::take_next_derivative_asset_id()?;
+			T::Assets::create(asset_id.clone(), Self::account_id(), false, min_balance)?;
+
+			PendingMultiLocationId::<T>::insert(asset_multi_location, asset_id.clone());
+			PendingRegistrations::<T>::insert(
+				asset_id.clone(),
+				PendingRegistration {
+					depositor: depositor.clone(),
+					deposit,
+					asset_multi_location,
+					metadata,
+				},
+			);
+
+			Self::deposit_event(Event::ReserveAssetRegistrationRequested {
+				asset_id,
+				asset_multi_location,
+				depositor,
+				deposit,
+			});
+			Ok(())
+		}
+
+		/// Approve a pending registration, registering the asset and releasing the deposit.
+		///
+		/// The requested metadata is also set in the `Assets` pallet, paid by the pallet account.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_reserve_asset_registration())]
+		pub fn approve_reserve_asset_registration(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+
+			let pending = Self::take_pending(asset_id.clone(), prefix.as_ref())?;
+			Self::ensure_location_unused(&pending.asset_multi_location)?;
+
+			<T::Currency as fungible::MutateHold<_>>::release(
+				&HoldReason::RegistrationDeposit.into(),
+				&pending.depositor,
+				pending.deposit,
+				Precision::BestEffort,
+			)?;
+
+			T::Assets::set(
+				asset_id.clone(),
+				&Self::account_id(),
+				pending.metadata.name.to_vec(),
+				pending.metadata.symbol.to_vec(),
+				pending.metadata.decimals,
+			)?;
+
+			Self::do_register(asset_id, pending.asset_multi_location, pending.metadata);
+			Ok(())
+		}
+
+		/// Reject a pending registration, slashing the deposit and starting the destruction of
+		/// the derivative.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_reserve_asset_registration())]
+		pub fn reject_reserve_asset_registration(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+
+			let pending = Self::take_pending(asset_id.clone(), prefix.as_ref())?;
+
+			let slashed = <T::Currency as fungible::MutateHold<_>>::burn_held(
+				&HoldReason::RegistrationDeposit.into(),
+				&pending.depositor,
+				pending.deposit,
+				Precision::BestEffort,
+				Fortitude::Force,
+			)?;
+			Self::destroy_derivative(asset_id.clone())?;
+
+			Self::deposit_event(Event::ReserveAssetRegistrationRejected {
+				asset_id,
+				depositor: pending.depositor,
+				slashed,
+			});
+			Ok(())
+		}
+
+		/// Decline a pending registration without penalty, releasing the deposit and starting the
+		/// destruction of the derivative.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::refund_reserve_asset_registration())]
+		pub fn refund_reserve_asset_registration(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+
+			let pending = Self::take_pending(asset_id.clone(), prefix.as_ref())?;
+
+			let refunded = <T::Currency as fungible::MutateHold<_>>::release(
+				&HoldReason::RegistrationDeposit.into(),
+				&pending.depositor,
+				pending.deposit,
+				Precision::BestEffort,
+			)?;
+			Self::destroy_derivative(asset_id.clone())?;
 
-			// remove asset_id => asset_multi_location, while getting the value
-			let asset_multi_location =
-				AssetIdMultiLocation::<T>::mutate_exists(asset_id.clone(), Option::take)
-					.ok_or(Error::<T>::AssetIsNotRegistered)?;
+			Self::deposit_event(Event::ReserveAssetRegistrationRefunded {
+				asset_id,
+				depositor: pending.depositor,
+				refunded,
+			});
+			Ok(())
+		}
+
+		/// Create the local derivative of the reserve asset at `asset_multi_location` and
+		/// register it, in a single step.
+		///
+		/// The derivative is given the next free id in `DerivativeAssetIdRange` and is owned by
+		/// the pallet account, which pays the metadata deposit of the `Assets` pallet.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_and_register_reserve_asset())]
+		pub fn create_and_register_reserve_asset(
+			origin: OriginFor<T>,
+			asset_multi_location: MultiLocation,
+			min_balance: AssetBalanceOf<T>,
+			is_sufficient: bool,
+			metadata: AssetMetadataOf<T>,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+
+			// verify MultiLocation is valid and not in use
+			ensure!(
+				Self::valid_asset_location(&asset_multi_location, prefix.as_ref()),
+				Error::<T>::WrongMultiLocation
+			);
+			Self::ensure_location_unused(&asset_multi_location)?;
+
+			let asset_id = Self::take_next_derivative_asset_id()?;
+			let owner = Self::account_id();
+			T::Assets::create(asset_id.clone(), owner.clone(), is_sufficient, min_balance)?;
+			T::Assets::set(
+				asset_id.clone(),
+				&owner,
+				metadata.name.to_vec(),
+				metadata.symbol.to_vec(),
+				metadata.decimals,
+			)?;
+
+			Self::do_register(asset_id, asset_multi_location, metadata);
+			Ok(())
+		}
+
+		/// Set the units of a registered asset charged per second of execution weight, making
+		/// the asset usable to pay for XCM execution. `None` stops accepting it as fee payment.
+		///
+		/// Only an unrestricted `ReserveAssetModifierOrigin` may price an asset.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_asset_units_per_second())]
+		pub fn set_asset_units_per_second(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			units_per_second: Option<u128>,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+			ensure!(prefix.is_none(), BadOrigin);
+
+			ensure!(
+				AssetIdMultiLocation::<T>::contains_key(asset_id.clone()),
+				Error::<T>::AssetIsNotRegistered
+			);
+
+			AssetIdUnitsPerSecond::<T>::set(asset_id.clone(), units_per_second);
+
+			Self::deposit_event(Event::AssetUnitsPerSecondUpdated { asset_id, units_per_second });
+			Ok(())
+		}
+
+		/// Freeze a registered asset, or release it from a freeze or quarantine by setting it
+		/// `Active`. Transfers of assets which are not active are refused.
+		///
+		/// Only an unrestricted `ReserveAssetModifierOrigin` may change the status of an asset.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_asset_status())]
+		pub fn set_asset_status(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			status: AssetStatus,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+			ensure!(prefix.is_none(), BadOrigin);
+
+			ensure!(
+				AssetIdMultiLocation::<T>::contains_key(asset_id.clone()),
+				Error::<T>::AssetIsNotRegistered
+			);
+
+			AssetIdStatus::<T>::insert(asset_id.clone(), status);
+
+			Self::deposit_event(Event::AssetStatusUpdated { asset_id, status });
+			Ok(())
+		}
+
+		/// Set the amounts of a registered asset allowed in and out of the chain per
+		/// `FlowLimitWindow`, or remove the limits with `None`.
+		///
+		/// Only an unrestricted `ReserveAssetModifierOrigin` may limit an asset.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_asset_flow_limits())]
+		pub fn set_asset_flow_limits(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			limits: Option<FlowLimits>,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+			ensure!(prefix.is_none(), BadOrigin);
+
+			ensure!(
+				AssetIdMultiLocation::<T>::contains_key(asset_id.clone()),
+				Error::<T>::AssetIsNotRegistered
+			);
+
+			AssetIdFlowLimits::<T>::set(asset_id.clone(), limits);
+			if limits.is_none() {
+				AssetIdFlow::<T>::remove(asset_id.clone());
+			}
+
+			Self::deposit_event(Event::AssetFlowLimitsUpdated { asset_id, limits });
+			Ok(())
+		}
+
+		/// Unregister a deprecated asset whose supply has reached zero.
+		///
+		/// Can be called by any signed origin.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_deprecated_reserve_asset())]
+		pub fn remove_deprecated_reserve_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				AssetIdStatus::<T>::get(asset_id.clone()) == AssetStatus::Deprecated,
+				Error::<T>::AssetIsNotDeprecated
+			);
+			ensure!(
+				T::Assets::total_issuance(asset_id.clone()).is_zero(),
+				Error::<T>::AssetHasSupply
+			);
+
+			let asset_multi_location = AssetIdMultiLocation::<T>::take(asset_id.clone())
+				.ok_or(Error::<T>::AssetIsNotRegistered)?;
+			Self::do_unregister(asset_id, asset_multi_location);
+			Ok(())
+		}
+
+		/// Unregister `asset_id` right away, even though derivatives of it are still held.
+		///
+		/// Only an unrestricted `ReserveAssetModifierOrigin` may force the removal of an asset.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_unregister_reserve_asset())]
+		pub fn force_unregister_reserve_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+			ensure!(prefix.is_none(), BadOrigin);
+
+			let asset_multi_location = AssetIdMultiLocation::<T>::take(asset_id.clone())
+				.ok_or(Error::<T>::AssetIsNotRegistered)?;
+			Self::do_unregister(asset_id, asset_multi_location);
+			Ok(())
+		}
+
+		/// Register the local collection `collection_id` as holding the derivatives of the
+		/// non-fungible collection at `collection_multi_location`.
+		///
+		/// Items of registered collections are trusted from the chain holding their reserve.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_reserve_collection())]
+		pub fn register_reserve_collection(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			collection_multi_location: MultiLocation,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				<T::Collections as nonfungibles::Inspect<_>>::collection_owner(&collection_id)
+					.is_some(),
+				Error::<T>::CollectionDoesNotExist
+			);
+			ensure!(
+				!CollectionIdMultiLocation::<T>::contains_key(collection_id.clone()),
+				Error::<T>::CollectionAlreadyRegistered
+			);
+			ensure!(
+				Self::valid_asset_location(&collection_multi_location, prefix.as_ref()),
+				Error::<T>::WrongMultiLocation
+			);
+			// a location is either a fungible asset or a collection
+			Self::ensure_location_unused(&collection_multi_location)?;
+
+			CollectionIdMultiLocation::<T>::insert(
+				collection_id.clone(),
+				collection_multi_location,
+			);
+			CollectionMultiLocationId::<T>::insert(
+				collection_multi_location,
+				collection_id.clone(),
+			);
+
+			Self::deposit_event(Event::ReserveCollectionRegistered {
+				collection_id,
+				collection_multi_location,
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unregister_reserve_collection())]
+		pub fn unregister_reserve_collection(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+		) -> DispatchResult {
+			let prefix = T::ReserveAssetModifierOrigin::ensure_origin(origin)?;
+
+			// remove collection_id => collection_multi_location, while getting the value
+			let collection_multi_location = CollectionIdMultiLocation::<T>::try_mutate_exists(
+				collection_id.clone(),
+				|maybe_location| -> Result<MultiLocation, DispatchError> {
+					let location =
+						maybe_location.take().ok_or(Error::<T>::CollectionIsNotRegistered)?;
+					ensure!(Self::within_prefix(&location, prefix.as_ref()), BadOrigin);
+					Ok(location)
+				},
+			)?;
+			// remove collection_multi_location => collection_id
+			CollectionMultiLocationId::<T>::remove(collection_multi_location);
+
+			Self::deposit_event(Event::ReserveCollectionUnregistered {
+				collection_id,
+				collection_multi_location,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		// Validates that the location points to an asset accepted by `AssetLocationFilter`.
+		// Origins restricted to a `prefix` may only use locations under it.
+		fn valid_asset_location(location: &MultiLocation, prefix: Option<&MultiLocation>) -> bool {
+			Self::within_prefix(location, prefix) && T::AssetLocationFilter::contains(location)
+		}
+
+		fn within_prefix(location: &MultiLocation, prefix: Option<&MultiLocation>) -> bool {
+			prefix.map_or(true, |prefix| location.starts_with(prefix))
+		}
+
+		// Ensures no asset, alias, collection or pending registration uses the location, so it
+		// resolves to a single local id.
+		fn ensure_location_unused(location: &MultiLocation) -> DispatchResult {
+			ensure!(
+				!AssetMultiLocationId::<T>::contains_key(location)
+					&& !AssetAliasMultiLocationId::<T>::contains_key(location)
+					&& !CollectionMultiLocationId::<T>::contains_key(location),
+				Error::<T>::LocationAlreadyRegistered
+			);
+			ensure!(
+				!PendingMultiLocationId::<T>::contains_key(location),
+				Error::<T>::LocationIsPending
+			);
+			Ok(())
+		}
+
+		/// The account owning the derivatives created by the registry.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		// Removes everything the registry keeps about `asset_id` once its entry in
+		// `AssetIdMultiLocation` was taken.
+		fn do_unregister(asset_id: AssetIdOf<T>, asset_multi_location: MultiLocation) {
 			// remove asset_multi_location => asset_id
 			AssetMultiLocationId::<T>::remove(asset_multi_location);
+			// remove asset_id => metadata
+			AssetIdMetadata::<T>::remove(asset_id.clone());
+			// remove asset_id => trust
+			AssetIdTrust::<T>::remove(asset_id.clone());
+			// remove asset_id => units_per_second
+			AssetIdUnitsPerSecond::<T>::remove(asset_id.clone());
+			// remove asset_id => status, flow limits and flow
+			AssetIdStatus::<T>::remove(asset_id.clone());
+			AssetIdFlowLimits::<T>::remove(asset_id.clone());
+			AssetIdFlow::<T>::remove(asset_id.clone());
+			// remove the alias of the asset, so it does not resolve to a later registration
+			if let Some(alias_multi_location) =
+				AssetIdAliasMultiLocation::<T>::take(asset_id.clone())
+			{
+				AssetAliasMultiLocationId::<T>::remove(alias_multi_location);
+			}
 
 			Self::deposit_event(Event::ReserveAssetUnregistered { asset_id, asset_multi_location });
+		}
+
+		/// Checks that `AssetIdMultiLocation` and `AssetMultiLocationId` are inverses of each
+		/// other, and that every registered asset exists in `Assets`. The collection maps, the
+		/// aliases and the pending registrations must be inverses of each other as well.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			let mut registered: u32 = 0;
+			for (asset_id, location) in AssetIdMultiLocation::<T>::iter() {
+				ensure!(
+					AssetMultiLocationId::<T>::get(location).as_ref() == Some(&asset_id),
+					"registered location does not resolve back to its asset"
+				);
+				ensure!(
+					T::Assets::asset_exists(asset_id.clone()),
+					"registered asset does not exist in the assets pallet"
+				);
+				registered.saturating_inc();
+			}
+			ensure!(
+				AssetMultiLocationId::<T>::iter_keys().count() as u32 == registered,
+				"a location resolves to an asset which is not registered"
+			);
+			ensure!(
+				AssetIdMetadata::<T>::iter_keys().all(AssetIdMultiLocation::<T>::contains_key),
+				"metadata is stored for an asset which is not registered"
+			);
+			for (asset_id, alias) in AssetIdAliasMultiLocation::<T>::iter() {
+				ensure!(
+					AssetIdMultiLocation::<T>::contains_key(asset_id.clone()),
+					"an alias is stored for an asset which is not registered"
+				);
+				ensure!(
+					AssetAliasMultiLocationId::<T>::get(alias).map(|(id, _)| id) == Some(asset_id),
+					"the alias of an asset does not resolve back to it"
+				);
+			}
+			let mut pending: u32 = 0;
+			for (asset_id, registration) in PendingRegistrations::<T>::iter() {
+				ensure!(
+					PendingMultiLocationId::<T>::get(registration.asset_multi_location).as_ref()
+						== Some(&asset_id),
+					"pending location does not resolve back to its registration"
+				);
+				pending.saturating_inc();
+			}
+			ensure!(
+				PendingMultiLocationId::<T>::iter_keys().count() as u32 == pending,
+				"a location resolves to a registration which is not pending"
+			);
+
+			let mut collections: u32 = 0;
+			for (collection_id, location) in CollectionIdMultiLocation::<T>::iter() {
+				ensure!(
+					CollectionMultiLocationId::<T>::get(location).as_ref() == Some(&collection_id),
+					"registered location does not resolve back to its collection"
+				);
+				collections.saturating_inc();
+			}
+			ensure!(
+				CollectionMultiLocationId::<T>::iter_keys().count() as u32 == collections,
+				"a location resolves to a collection which is not registered"
+			);
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		//Validates that the location points to an asset (Native, Frame based, Erc20) as described
-		// in the xcm-format:  https://github.com/paritytech/xcm-format#concrete-identifiers
-		fn valid_asset_location(location: &MultiLocation) -> bool {
-			let (split_multilocation, last_junction) = location.split_last_interior();
-
-			let check = matches!(
-				last_junction,
-				Some(AccountId32 { .. })
-					| Some(AccountKey20 { .. })
-					| Some(PalletInstance(_))
-					| Some(Parachain(_)) | None
-			);
-
-			check
-				| match last_junction {
-					Some(GeneralIndex(_)) => {
-						let penultimate = split_multilocation.last();
-						matches!(penultimate, Some(PalletInstance(_)))
-					},
-					_ => false,
+		// Adds `amount` to the flow of the asset at `asset_location`, refusing it when it exceeds
+		// the limit of the asset in `direction`.
+		fn note_flow(
+			asset_location: &MultiLocation,
+			amount: u128,
+			direction: FlowDirection,
+		) -> Result<(), XcmError> {
+			let Some(asset_id) = Self::get_asset_id(asset_location) else { return Ok(()) };
+			let Some(limits) = AssetIdFlowLimits::<T>::get(asset_id.clone()) else { return Ok(()) };
+			let limit = match direction {
+				FlowDirection::Inbound => limits.inbound,
+				FlowDirection::Outbound => limits.outbound,
+			};
+			let Some(limit) = limit else { return Ok(()) };
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut flow = AssetIdFlow::<T>::get(asset_id.clone());
+			if now >= flow.window_start.saturating_add(T::FlowLimitWindow::get()) {
+				flow = AssetFlow { window_start: now, inbound: 0, outbound: 0 };
+			}
+			let (noted, error) = match direction {
+				FlowDirection::Inbound => (&mut flow.inbound, XcmError::NotDepositable),
+				FlowDirection::Outbound => (&mut flow.outbound, XcmError::NotWithdrawable),
+			};
+
+			let total = noted.saturating_add(amount);
+			if total > limit {
+				Self::deposit_event(Event::AssetFlowLimitExceeded {
+					asset_id: asset_id.clone(),
+					direction,
+					amount,
+				});
+				if limits.quarantine {
+					AssetIdStatus::<T>::insert(asset_id.clone(), AssetStatus::Quarantined);
+					Self::deposit_event(Event::AssetStatusUpdated {
+						asset_id,
+						status: AssetStatus::Quarantined,
+					});
 				}
+				return Err(error);
+			}
+
+			*noted = total;
+			AssetIdFlow::<T>::insert(asset_id, flow);
+			Ok(())
+		}
+
+		fn is_derivative_asset_id(asset_id: &AssetIdOf<T>) -> bool {
+			let (first, last) = T::DerivativeAssetIdRange::get();
+			first <= *asset_id && *asset_id <= last
+		}
+
+		// Returns the first id from `NextDerivativeAssetId` not in use by the `Assets` pallet,
+		// moving `NextDerivativeAssetId` past it.
+		fn take_next_derivative_asset_id() -> Result<AssetIdOf<T>, DispatchError> {
+			let (first, last) = T::DerivativeAssetIdRange::get();
+			let mut asset_id =
+				NextDerivativeAssetId::<T>::get().unwrap_or(first.clone()).max(first);
+			// skip ids taken by assets created outside of the registry
+			while T::Assets::asset_exists(asset_id.clone()) {
+				ensure!(asset_id < last, Error::<T>::NoDerivativeAssetIdAvailable);
+				asset_id = asset_id.increment().ok_or(Error::<T>::NoDerivativeAssetIdAvailable)?;
+			}
+			ensure!(asset_id <= last, Error::<T>::NoDerivativeAssetIdAvailable);
+
+			// once the range is exhausted, `last` stays in use and the next call fails
+			NextDerivativeAssetId::<T>::put(asset_id.increment().unwrap_or(asset_id.clone()));
+			Ok(asset_id)
+		}
+
+		fn do_register(
+			asset_id: AssetIdOf<T>,
+			asset_multi_location: MultiLocation,
+			metadata: AssetMetadataOf<T>,
+		) {
+			// register asset_id => asset_multi_location
+			AssetIdMultiLocation::<T>::insert(asset_id.clone(), asset_multi_location);
+			// register asset_multi_location => asset_id
+			AssetMultiLocationId::<T>::insert(asset_multi_location, asset_id.clone());
+			// register asset_id => metadata
+			AssetIdMetadata::<T>::insert(asset_id.clone(), metadata.clone());
+
+			Self::deposit_event(Event::ReserveAssetRegistered {
+				asset_id,
+				asset_multi_location,
+				metadata,
+			});
+		}
+
+		// Removes the pending registration of `asset_id`, if its location is under `prefix`.
+		fn take_pending(
+			asset_id: AssetIdOf<T>,
+			prefix: Option<&MultiLocation>,
+		) -> Result<PendingRegistrationOf<T>, DispatchError> {
+			PendingRegistrations::<T>::try_mutate_exists(
+				asset_id,
+				|maybe_pending| -> Result<PendingRegistrationOf<T>, DispatchError> {
+					let pending = maybe_pending.take().ok_or(Error::<T>::RegistrationNotPending)?;
+					ensure!(Self::within_prefix(&pending.asset_multi_location, prefix), BadOrigin);
+					PendingMultiLocationId::<T>::remove(pending.asset_multi_location);
+					Ok(pending)
+				},
+			)
+		}
+
+		// Starts destroying the derivative created for a declined registration. The remaining
+		// steps are permissionless in the `Assets` pallet, as accounts touched in the meantime
+		// may need several calls to be removed.
+		fn destroy_derivative(asset_id: AssetIdOf<T>) -> DispatchResult {
+			T::Assets::start_destroy(asset_id, None)
+		}
+
+		/// Location of the reserve asset registered as `asset_id`.
+		pub fn asset_location(asset_id: AssetIdOf<T>) -> Option<VersionedMultiLocation> {
+			AssetIdMultiLocation::<T>::get(asset_id).map(Into::into)
+		}
+
+		/// Local asset id of the reserve asset at `location`.
+		pub fn asset_id(location: VersionedMultiLocation) -> Option<AssetIdOf<T>> {
+			let location = MultiLocation::try_from(location).ok()?;
+			Self::get_asset_id(&location)
+		}
+
+		/// All registered reserve assets.
+		pub fn registered_assets() -> Vec<(AssetIdOf<T>, VersionedMultiLocation)> {
+			AssetIdMultiLocation::<T>::iter()
+				.map(|(asset_id, location)| (asset_id, location.into()))
+				.collect()
+		}
+
+		/// Registered reserve assets whose reserve is the chain at `reserve`.
+		pub fn assets_by_reserve(
+			reserve: VersionedMultiLocation,
+		) -> Vec<(AssetIdOf<T>, VersionedMultiLocation)> {
+			let Ok(reserve) = MultiLocation::try_from(reserve) else { return Vec::new() };
+
+			AssetIdMultiLocation::<T>::iter()
+				.filter(|(_, location)| reserve_chain(location) == reserve)
+				.map(|(asset_id, location)| (asset_id, location.into()))
+				.collect()
+		}
+
+		// Reads the metadata of the local derivative from the `Assets` pallet.
+		fn local_metadata(asset_id: AssetIdOf<T>) -> Result<AssetMetadataOf<T>, DispatchError> {
+			let bounded =
+				|value: Vec<u8>| BoundedVec::try_from(value).map_err(|_| Error::<T>::BadMetadata);
+			let decimals = T::Assets::decimals(asset_id.clone());
+
+			Ok(AssetMetadata {
+				name: bounded(T::Assets::name(asset_id.clone()))?,
+				symbol: bounded(T::Assets::symbol(asset_id))?,
+				decimals,
+				reserve_decimals: decimals,
+			})
 		}
 	}
 
-	impl<T: Config> xcm_primitives::AssetMultiLocationGetter<AssetIdOf<T>> for Pallet<T> {
+	impl<T: Config> AssetMultiLocationGetter<AssetIdOf<T>, MultiLocation> for Pallet<T> {
 		fn get_asset_multi_location(asset_id: AssetIdOf<T>) -> Option<MultiLocation> {
 			AssetIdMultiLocation::<T>::get(asset_id)
 		}
 
 		fn get_asset_id(asset_type: &MultiLocation) -> Option<AssetIdOf<T>> {
-			AssetMultiLocationId::<T>::get(asset_type)
+			AssetMultiLocationId::<T>::get(asset_type).or_else(|| {
+				// fall back to aliases which have not expired yet, and are still the alias of their
+				// asset
+				let (asset_id, expiry) = AssetAliasMultiLocationId::<T>::get(asset_type)?;
+				(expiry > frame_system::Pallet::<T>::block_number()
+					&& AssetIdAliasMultiLocation::<T>::get(asset_id.clone()).as_ref()
+						== Some(asset_type))
+				.then_some(asset_id)
+			})
 		}
 	}
+
+	impl<T: Config> AssetTrustGetter for Pallet<T> {
+		fn get_asset_trust(asset_multi_location: &MultiLocation) -> Option<AssetTrust> {
+			Self::get_asset_id(asset_multi_location)
+				.map(AssetIdTrust::<T>::get)
+				.or_else(|| {
+					// items of registered collections are reserve-backed
+					CollectionMultiLocationId::<T>::contains_key(asset_multi_location)
+						.then_some(AssetTrust::Reserve)
+				})
+		}
+	}
+
+	impl<T: Config> CollectionMultiLocationGetter<T::CollectionId> for Pallet<T> {
+		fn get_collection_multi_location(collection_id: T::CollectionId) -> Option<MultiLocation> {
+			CollectionIdMultiLocation::<T>::get(collection_id)
+		}
+
+		fn get_collection_id(collection_multi_location: &MultiLocation) -> Option<T::CollectionId> {
+			CollectionMultiLocationId::<T>::get(collection_multi_location)
+		}
+	}
+
+	impl<T: Config> UnitsPerSecondGetter for Pallet<T> {
+		fn get_units_per_second(asset_multi_location: &MultiLocation) -> Option<u128> {
+			Self::get_asset_id(asset_multi_location).and_then(AssetIdUnitsPerSecond::<T>::get)
+		}
+	}
+
+	/// Unregisters assets destroyed in `pallet-assets`, when set as its `CallbackHandle`.
+	impl<T: Config> pallet_assets::AssetsCallback<AssetIdOf<T>, T::AccountId> for Pallet<T> {
+		fn destroyed(asset_id: &AssetIdOf<T>) -> Result<(), ()> {
+			if let Some(asset_multi_location) = AssetIdMultiLocation::<T>::take(asset_id) {
+				Self::do_unregister(asset_id.clone(), asset_multi_location);
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> AssetStatusGetter<AssetIdOf<T>> for Pallet<T> {
+		fn get_asset_status(asset_id: &AssetIdOf<T>) -> AssetStatus {
+			AssetIdStatus::<T>::get(asset_id)
+		}
+	}
+
+	impl<T: Config> AssetFlowLimiter for Pallet<T> {
+		fn note_inbound(asset_location: &MultiLocation, amount: u128) -> Result<(), XcmError> {
+			// deprecated assets are on their way out, no more of them may arrive
+			if let Some(asset_id) = Self::get_asset_id(asset_location) {
+				if AssetIdStatus::<T>::get(asset_id) == AssetStatus::Deprecated {
+					return Err(XcmError::NotDepositable);
+				}
+			}
+			Self::note_flow(asset_location, amount, FlowDirection::Inbound)
+		}
+
+		fn note_outbound(asset_location: &MultiLocation, amount: u128) -> Result<(), XcmError> {
+			Self::note_flow(asset_location, amount, FlowDirection::Outbound)
+		}
+	}
+
+	impl<T: Config> RecordLostAsset for Pallet<T> {
+		fn record_lost_asset(origin: &MultiLocation, asset_id: &XcmAssetId, amount: u128) {
+			Self::deposit_event(Event::AssetLost { origin: *origin, asset_id: *asset_id, amount });
+		}
+	}
+
+	impl<T: Config> xcm_primitives::AssetDecimalsGetter<AssetIdOf<T>> for Pallet<T> {
+		fn get_asset_decimals(asset_id: &AssetIdOf<T>) -> Option<AssetDecimals> {
+			AssetIdMetadata::<T>::get(asset_id).map(|metadata| AssetDecimals {
+				local: metadata.decimals,
+				reserve: metadata.reserve_decimals,
+			})
+		}
+	}
+}
+
+/// Accepts origins of `Inner`, restricting them to asset locations under the location `Inner`
+/// resolves to.
+///
+/// Combined with `pallet_xcm::EnsureXcm`, this lets a reserve chain register its own assets.
+pub struct EnsureReserveChain<Inner>(sp_std::marker::PhantomData<Inner>);
+impl<O, Inner> frame_support::traits::EnsureOrigin<O> for EnsureReserveChain<Inner>
+where
+	Inner: frame_support::traits::EnsureOrigin<O, Success = xcm::latest::MultiLocation>,
+{
+	type Success = Option<xcm::latest::MultiLocation>;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		Inner::try_origin(o).map(Some)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Inner::try_successful_origin()
+	}
+}
+
+/// Asset locations following the xcm-format conventions for concrete identifiers within a
+/// consensus system: native assets of chains, accounts and contracts, pallets, and assets
+/// identified by a key or by indices nested under a pallet.
+///
+/// See <https://github.com/paritytech/xcm-format#concrete-identifiers>.
+pub struct StandardAssetLocations;
+impl frame_support::traits::Contains<MultiLocation> for StandardAssetLocations {
+	fn contains(location: &MultiLocation) -> bool {
+		if location.interior.iter().any(|junction| matches!(junction, GlobalConsensus(_))) {
+			return false;
+		}
+
+		let (parent, last_junction) = location.split_last_interior();
+		match last_junction {
+			None
+			| Some(AccountId32 { .. })
+			| Some(AccountKey20 { .. })
+			| Some(GeneralKey { .. })
+			| Some(PalletInstance(_))
+			| Some(Parachain(_)) => true,
+			// nested indices, e.g. `PalletInstance(51)/GeneralIndex(1)/GeneralIndex(7)`
+			Some(GeneralIndex(_)) => matches!(
+				parent
+					.interior
+					.iter()
+					.rev()
+					.find(|junction| !matches!(junction, GeneralIndex(_))),
+				Some(PalletInstance(_))
+			),
+			_ => false,
+		}
+	}
+}
+
+/// Locations of assets from another consensus system, i.e. starting with `GlobalConsensus`,
+/// whose location within that consensus system is accepted by `Inner`.
+///
+/// E.g. `(2, GlobalConsensus(Ethereum { chain_id: 1 }), AccountKey20 { .. })` for an ERC-20.
+pub struct BridgedAssetLocations<Inner>(sp_std::marker::PhantomData<Inner>);
+impl<Inner: frame_support::traits::Contains<MultiLocation>>
+	frame_support::traits::Contains<MultiLocation> for BridgedAssetLocations<Inner>
+{
+	fn contains(location: &MultiLocation) -> bool {
+		let (within_consensus, first_junction) = location.interior.split_first();
+		matches!(first_junction, Some(GlobalConsensus(_)))
+			&& Inner::contains(&MultiLocation::new(0, within_consensus))
+	}
 }
@@ -16,13 +16,17 @@
 // limitations under the License.
 
 use crate as pallet_asset_registry;
-use frame_support::traits::{AsEnsureOriginWithArg, ConstU16, ConstU64};
+use frame_support::traits::{
+	AsEnsureOriginWithArg, ConstU16, ConstU64, EitherOf, EnsureOrigin, GetDefault,
+};
+use frame_support::PalletId;
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, ConstU32, IdentityLookup},
 	BuildStorage,
 };
+use xcm::latest::{Junction::Parachain, Junctions::X1, MultiLocation};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -30,15 +34,18 @@ frame_support::parameter_types! {
 	pub const StatemineParaIdInfo: u32 = 1000u32;
 	pub const StatemineAssetsInstanceInfo: u8 = 50u8;
 	pub const StatemineAssetIdInfo: u128 = 1u128;
+	pub const AssetRegistryPalletId: PalletId = PalletId(*b"asst/reg");
+	pub const DerivativeAssetIdRange: (u32, u32) = (FIRST_DERIVATIVE_ASSET_ID, 1_002);
 }
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub struct Test {
 		System: frame_system,
-		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Event<T>},
+		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Event<T>, HoldReason},
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -71,16 +78,62 @@ impl system::Config for Test {
 #[cfg(feature = "runtime-benchmarks")]
 pub struct MockAssetRegistryBenchmarkHelper;
 #[cfg(feature = "runtime-benchmarks")]
-impl pallet_asset_registry::BenchmarkHelper<u32> for MockAssetRegistryBenchmarkHelper {
+impl pallet_asset_registry::BenchmarkHelper<u32, u32> for MockAssetRegistryBenchmarkHelper {
 	fn get_registered_asset() -> u32 {
-		LOCAL_ASSET_ID
+		EMPTY_ASSET_ID
+	}
+
+	fn get_registered_collection() -> u32 {
+		LOCAL_COLLECTION_ID
+	}
+}
+
+/// Account standing in for the XCM origin of the Statemine parachain.
+pub const STATEMINE_ORIGIN_ACCOUNT: u64 = 1000;
+
+/// Resolves signed origins of `STATEMINE_ORIGIN_ACCOUNT` to the location of Statemine, the way
+/// `pallet_xcm::EnsureXcm` resolves XCM origins.
+pub struct EnsureStatemine;
+impl EnsureOrigin<RuntimeOrigin> for EnsureStatemine {
+	type Success = MultiLocation;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		let origin: Result<system::RawOrigin<u64>, RuntimeOrigin> = o.clone().into();
+		match origin {
+			Ok(system::RawOrigin::Signed(STATEMINE_ORIGIN_ACCOUNT)) => {
+				Ok(MultiLocation::new(1, X1(Parachain(StatemineParaIdInfo::get()))))
+			},
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT))
 	}
 }
 
 impl pallet_asset_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type ReserveAssetModifierOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ReserveAssetModifierOrigin = EitherOf<
+		frame_system::EnsureRootWithSuccess<Self::AccountId, GetDefault>,
+		pallet_asset_registry::EnsureReserveChain<EnsureStatemine>,
+	>;
+	type AssetId = u32;
 	type Assets = Assets;
+	type DerivativeAssetIdRange = DerivativeAssetIdRange;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type RegistrationDeposit = ConstU64<REGISTRATION_DEPOSIT>;
+	type PalletId = AssetRegistryPalletId;
+	type StringLimit = ConstU32<50>;
+	type AssetLocationFilter = (
+		pallet_asset_registry::StandardAssetLocations,
+		pallet_asset_registry::BridgedAssetLocations<pallet_asset_registry::StandardAssetLocations>,
+	);
+	type FlowLimitWindow = ConstU64<FLOW_LIMIT_WINDOW>;
+	type CollectionId = u32;
+	type Collections = Uniques;
 	type WeightInfo = pallet_asset_registry::weights::SubstrateWeight<Test>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = MockAssetRegistryBenchmarkHelper;
@@ -94,7 +147,7 @@ impl pallet_balances::Config for Test {
 	type ExistentialDeposit = ConstU64<1>;
 	type AccountStore = System;
 	type ReserveIdentifier = [u8; 8];
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type FreezeIdentifier = ();
 	type MaxLocks = ();
 	type MaxReserves = ();
@@ -120,13 +173,45 @@ impl pallet_assets::Config for Test {
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
 	type Extra = ();
-	type CallbackHandle = ();
+	type CallbackHandle = AssetRegistry;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
 
+impl pallet_uniques::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+	type Locker = ();
+	type CollectionDeposit = ConstU64<1>;
+	type ItemDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type DepositPerByte = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type WeightInfo = ();
+}
+
 pub const LOCAL_ASSET_ID: u32 = 10;
+/// Asset id given to the first registration requested with a deposit.
+pub const PENDING_ASSET_ID: u32 = FIRST_DERIVATIVE_ASSET_ID;
+/// Asset id created in genesis without any supply, so it is unregistered right away.
+pub const EMPTY_ASSET_ID: u32 = 30;
+pub const REGISTRATION_DEPOSIT: u64 = 100;
+/// Account with enough balance to request a registration.
+pub const DEPOSITOR: u64 = 2;
+pub const FIRST_DERIVATIVE_ASSET_ID: u32 = 1_000;
+pub const FLOW_LIMIT_WINDOW: u64 = 10;
+/// Collection created by `new_test_ext`, holding derivatives of a remote collection.
+pub const LOCAL_COLLECTION_ID: u32 = 10;
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -136,6 +221,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		assets: vec![
 			// id, owner, is_sufficient, min_balance
 			(LOCAL_ASSET_ID, 0, true, 1),
+			(EMPTY_ASSET_ID, 0, true, 1),
 		],
 		metadata: vec![
 			// id, name, symbol, decimals
@@ -147,5 +233,19 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		],
 	};
 	config.assimilate_storage(&mut storage).unwrap();
-	storage.into()
+
+	pallet_balances::GenesisConfig::<Test> {
+		// the registry account pays the metadata deposit of the derivatives it creates
+		balances: vec![(DEPOSITOR, 1_000), (AssetRegistry::account_id(), 1_000)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| {
+		// pallet-uniques has no genesis config
+		Uniques::force_create(RuntimeOrigin::root(), LOCAL_COLLECTION_ID, 0, true).unwrap();
+		System::set_block_number(1)
+	});
+	ext
 }
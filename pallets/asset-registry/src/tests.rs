@@ -16,9 +16,13 @@
 // limitations under the License.
 
 use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
 use xcm::latest::prelude::*;
 
-use crate::{mock::*, AssetIdMultiLocation, AssetMultiLocationId, Error};
+use crate::{
+	mock::*, AssetAliasMultiLocationId, AssetIdAliasMultiLocation, AssetIdMetadata,
+	AssetIdMultiLocation, AssetIdTrust, AssetMetadata, AssetMultiLocationId, Error,
+};
 
 const STATEMINE_ASSET_MULTI_LOCATION: MultiLocation = MultiLocation {
 	parents: 1,
@@ -29,6 +33,64 @@ const STATEMINE_ASSET_MULTI_LOCATION: MultiLocation = MultiLocation {
 	),
 };
 
+// Burns the supply of `LOCAL_ASSET_ID` minted in genesis, so it is unregistered right away.
+fn burn_local_asset_supply() {
+	assert_ok!(Assets::burn(RuntimeOrigin::signed(0), LOCAL_ASSET_ID, 1, 100));
+}
+
+// Uses a location for each entry a registration can collide with: a registered asset, an alias,
+// a collection and a pending registration. Each location is returned with the error raised by
+// registering it again.
+fn occupy_locations() -> [(MultiLocation, DispatchError); 4] {
+	let location = |index| {
+		MultiLocation::new(
+			1,
+			X3(
+				Parachain(StatemineParaIdInfo::get()),
+				PalletInstance(StatemineAssetsInstanceInfo::get()),
+				GeneralIndex(index),
+			),
+		)
+	};
+
+	// `LOCAL_ASSET_ID` moves to the second location, keeping the first one as an alias
+	assert_ok!(AssetRegistry::register_reserve_asset(
+		RuntimeOrigin::root(),
+		LOCAL_ASSET_ID,
+		location(1),
+		None,
+	));
+	assert_ok!(AssetRegistry::update_reserve_asset_location(
+		RuntimeOrigin::root(),
+		LOCAL_ASSET_ID,
+		location(2),
+		Some(10),
+	));
+	assert_ok!(AssetRegistry::register_reserve_collection(
+		RuntimeOrigin::root(),
+		LOCAL_COLLECTION_ID,
+		location(3),
+	));
+	assert_ok!(AssetRegistry::register_reserve_asset_with_deposit(
+		RuntimeOrigin::signed(DEPOSITOR),
+		location(4),
+		AssetMetadata {
+			name: b"Pending Token".to_vec().try_into().unwrap(),
+			symbol: b"PND".to_vec().try_into().unwrap(),
+			decimals: 12,
+			reserve_decimals: 12,
+		},
+		1,
+	));
+
+	[
+		(location(1), Error::<Test>::LocationAlreadyRegistered.into()),
+		(location(2), Error::<Test>::LocationAlreadyRegistered.into()),
+		(location(3), Error::<Test>::LocationAlreadyRegistered.into()),
+		(location(4), Error::<Test>::LocationIsPending.into()),
+	]
+}
+
 mod register_reserve_assest {
 	use super::*;
 
@@ -39,6 +101,7 @@ mod register_reserve_assest {
 				RuntimeOrigin::root(),
 				LOCAL_ASSET_ID,
 				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
 			));
 
 			assert_eq!(
@@ -52,6 +115,85 @@ mod register_reserve_assest {
 		});
 	}
 
+	#[test]
+	fn register_reserve_asset_derives_metadata() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert_eq!(
+				AssetIdMetadata::<Test>::get(LOCAL_ASSET_ID),
+				Some(AssetMetadata {
+					name: b"Token Name".to_vec().try_into().unwrap(),
+					symbol: b"TOKEN".to_vec().try_into().unwrap(),
+					decimals: 10,
+					reserve_decimals: 10,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn register_reserve_asset_stores_provided_metadata() {
+		new_test_ext().execute_with(|| {
+			let metadata = AssetMetadata {
+				name: b"Reserve Token".to_vec().try_into().unwrap(),
+				symbol: b"rTOKEN".to_vec().try_into().unwrap(),
+				decimals: 10,
+				reserve_decimals: 12,
+			};
+
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				Some(metadata.clone()),
+			));
+
+			assert_eq!(AssetIdMetadata::<Test>::get(LOCAL_ASSET_ID), Some(metadata.clone()));
+			assert_eq!(
+				<AssetRegistry as xcm_primitives::AssetDecimalsGetter<u32>>::get_asset_decimals(
+					&LOCAL_ASSET_ID
+				),
+				Some(xcm_primitives::AssetDecimals { local: 10, reserve: 12 })
+			);
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveAssetRegistered {
+					asset_id: LOCAL_ASSET_ID,
+					asset_multi_location: STATEMINE_ASSET_MULTI_LOCATION,
+					metadata,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_register_with_mismatched_decimals() {
+		new_test_ext().execute_with(|| {
+			let metadata = AssetMetadata {
+				name: b"Reserve Token".to_vec().try_into().unwrap(),
+				symbol: b"rTOKEN".to_vec().try_into().unwrap(),
+				decimals: 12,
+				reserve_decimals: 12,
+			};
+
+			assert_noop!(
+				AssetRegistry::register_reserve_asset(
+					RuntimeOrigin::root(),
+					LOCAL_ASSET_ID,
+					STATEMINE_ASSET_MULTI_LOCATION,
+					Some(metadata),
+				),
+				Error::<Test>::DecimalsMismatch
+			);
+		});
+	}
+
 	#[test]
 	fn cannot_register_unexisting_asset() {
 		new_test_ext().execute_with(|| {
@@ -62,6 +204,7 @@ mod register_reserve_assest {
 					RuntimeOrigin::root(),
 					unexisting_asset_id,
 					STATEMINE_ASSET_MULTI_LOCATION,
+					None,
 				),
 				Error::<Test>::AssetDoesNotExist
 			);
@@ -75,6 +218,7 @@ mod register_reserve_assest {
 				RuntimeOrigin::root(),
 				LOCAL_ASSET_ID,
 				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
 			));
 
 			assert_noop!(
@@ -82,12 +226,30 @@ mod register_reserve_assest {
 					RuntimeOrigin::root(),
 					LOCAL_ASSET_ID,
 					STATEMINE_ASSET_MULTI_LOCATION,
+					None,
 				),
 				Error::<Test>::AssetAlreadyRegistered
 			);
 		});
 	}
 
+	#[test]
+	fn cannot_register_used_location() {
+		new_test_ext().execute_with(|| {
+			for (location, error) in occupy_locations() {
+				assert_noop!(
+					AssetRegistry::register_reserve_asset(
+						RuntimeOrigin::root(),
+						EMPTY_ASSET_ID,
+						location,
+						None,
+					),
+					error
+				);
+			}
+		});
+	}
+
 	#[test]
 	fn valid_locations_succeed() {
 		let native_frame_based_currency =
@@ -118,6 +280,7 @@ mod register_reserve_assest {
 				RuntimeOrigin::root(),
 				LOCAL_ASSET_ID,
 				native_frame_based_currency,
+				None,
 			));
 		});
 		new_test_ext().execute_with(|| {
@@ -125,6 +288,7 @@ mod register_reserve_assest {
 				RuntimeOrigin::root(),
 				LOCAL_ASSET_ID,
 				multiasset_pallet_instance,
+				None,
 			));
 		});
 		new_test_ext().execute_with(|| {
@@ -132,6 +296,7 @@ mod register_reserve_assest {
 				RuntimeOrigin::root(),
 				LOCAL_ASSET_ID,
 				relay_native_currency,
+				None,
 			));
 		});
 		new_test_ext().execute_with(|| {
@@ -139,6 +304,7 @@ mod register_reserve_assest {
 				RuntimeOrigin::root(),
 				LOCAL_ASSET_ID,
 				erc20_frame_sm_asset,
+				None,
 			));
 		});
 		new_test_ext().execute_with(|| {
@@ -146,6 +312,7 @@ mod register_reserve_assest {
 				RuntimeOrigin::root(),
 				LOCAL_ASSET_ID,
 				erc20_ethereum_sm_asset,
+				None,
 			));
 		});
 	}
@@ -168,6 +335,7 @@ mod register_reserve_assest {
 					RuntimeOrigin::root(),
 					LOCAL_ASSET_ID,
 					governance_location,
+					None,
 				),
 				Error::<Test>::WrongMultiLocation
 			);
@@ -177,6 +345,7 @@ mod register_reserve_assest {
 					RuntimeOrigin::root(),
 					LOCAL_ASSET_ID,
 					invalid_general_index,
+					None,
 				),
 				Error::<Test>::WrongMultiLocation
 			);
@@ -184,6 +353,112 @@ mod register_reserve_assest {
 	}
 }
 
+mod asset_location {
+	use super::*;
+	use crate::{BridgedAssetLocations, StandardAssetLocations};
+	use frame_support::traits::Contains;
+
+	type Filter = (StandardAssetLocations, BridgedAssetLocations<StandardAssetLocations>);
+
+	const ETHEREUM: NetworkId = NetworkId::Ethereum { chain_id: 1 };
+
+	fn general_key() -> Junction {
+		GeneralKey { length: 2, data: [1; 32] }
+	}
+
+	#[test]
+	fn standard_locations_are_accepted() {
+		for location in [
+			MultiLocation::parent(),
+			MultiLocation::new(1, X1(Parachain(2000))),
+			MultiLocation::new(1, X2(Parachain(2000), PalletInstance(10))),
+			MultiLocation::new(
+				1,
+				X2(Parachain(2000), AccountKey20 { network: None, key: [1; 20] }),
+			),
+			MultiLocation::new(1, X2(Parachain(2000), AccountId32 { network: None, id: [1; 32] })),
+			STATEMINE_ASSET_MULTI_LOCATION,
+		] {
+			assert!(Filter::contains(&location), "{:?} is not accepted", location);
+		}
+	}
+
+	#[test]
+	fn general_key_locations_are_accepted() {
+		assert!(Filter::contains(&MultiLocation::new(1, X2(Parachain(2000), general_key()))));
+		assert!(Filter::contains(&MultiLocation::new(
+			1,
+			X3(Parachain(2000), PalletInstance(10), general_key())
+		)));
+	}
+
+	#[test]
+	fn nested_indices_under_a_pallet_are_accepted() {
+		assert!(Filter::contains(&MultiLocation::new(
+			1,
+			X4(Parachain(1000), PalletInstance(51), GeneralIndex(1), GeneralIndex(7))
+		)));
+		assert!(!Filter::contains(&MultiLocation::new(
+			1,
+			X3(Parachain(1000), GeneralIndex(1), GeneralIndex(7))
+		)));
+		assert!(!Filter::contains(&MultiLocation::new(
+			1,
+			X4(Parachain(1000), PalletInstance(51), general_key(), GeneralIndex(7))
+		)));
+	}
+
+	#[test]
+	fn bridged_locations_are_accepted() {
+		let erc20 = MultiLocation::new(
+			2,
+			X2(GlobalConsensus(ETHEREUM), AccountKey20 { network: None, key: [1; 20] }),
+		);
+		let ether = MultiLocation::new(2, X1(GlobalConsensus(ETHEREUM)));
+		let remote_asset = MultiLocation::new(
+			2,
+			X4(
+				GlobalConsensus(NetworkId::Kusama),
+				Parachain(1000),
+				PalletInstance(50),
+				GeneralIndex(1),
+			),
+		);
+
+		for location in [erc20, ether, remote_asset] {
+			assert!(!StandardAssetLocations::contains(&location));
+			assert!(Filter::contains(&location), "{:?} is not accepted", location);
+		}
+		assert!(!Filter::contains(&MultiLocation::new(
+			2,
+			X2(GlobalConsensus(ETHEREUM), GlobalConsensus(NetworkId::Kusama))
+		)));
+		assert!(!Filter::contains(&MultiLocation::new(
+			2,
+			X2(GlobalConsensus(ETHEREUM), GeneralIndex(1))
+		)));
+	}
+
+	#[test]
+	fn register_reserve_asset_uses_the_filter() {
+		new_test_ext().execute_with(|| {
+			let erc20 = MultiLocation::new(
+				2,
+				X2(GlobalConsensus(ETHEREUM), AccountKey20 { network: None, key: [1; 20] }),
+			);
+
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				erc20,
+				None,
+			));
+
+			assert_eq!(AssetMultiLocationId::<Test>::get(erc20), Some(LOCAL_ASSET_ID));
+		});
+	}
+}
+
 mod unregister_reserve_asset {
 	use super::*;
 
@@ -194,7 +469,9 @@ mod unregister_reserve_asset {
 				RuntimeOrigin::root(),
 				LOCAL_ASSET_ID,
 				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
 			));
+			burn_local_asset_supply();
 
 			assert_ok!(AssetRegistry::unregister_reserve_asset(
 				RuntimeOrigin::root(),
@@ -203,6 +480,7 @@ mod unregister_reserve_asset {
 
 			assert!(AssetIdMultiLocation::<Test>::get(LOCAL_ASSET_ID).is_none());
 			assert!(AssetMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			assert!(AssetIdMetadata::<Test>::get(LOCAL_ASSET_ID).is_none());
 		});
 	}
 
@@ -216,3 +494,1938 @@ mod unregister_reserve_asset {
 		});
 	}
 }
+
+mod update_reserve_asset_location {
+	use super::*;
+	use frame_support::error::BadOrigin;
+	use xcm_primitives::AssetMultiLocationGetter;
+
+	const NEW_STATEMINE_ASSET_MULTI_LOCATION: MultiLocation = MultiLocation {
+		parents: 1,
+		interior: X3(
+			Parachain(StatemineParaIdInfo::get()),
+			PalletInstance(StatemineAssetsInstanceInfo::get() + 1),
+			GeneralIndex(StatemineAssetIdInfo::get()),
+		),
+	};
+
+	const MOVED_STATEMINE_ASSET_MULTI_LOCATION: MultiLocation = MultiLocation {
+		parents: 1,
+		interior: X3(
+			Parachain(StatemineParaIdInfo::get()),
+			PalletInstance(StatemineAssetsInstanceInfo::get() + 2),
+			GeneralIndex(StatemineAssetIdInfo::get()),
+		),
+	};
+
+	fn register_statemine_asset() {
+		assert_ok!(AssetRegistry::register_reserve_asset(
+			RuntimeOrigin::root(),
+			LOCAL_ASSET_ID,
+			STATEMINE_ASSET_MULTI_LOCATION,
+			None,
+		));
+	}
+
+	#[test]
+	fn update_reserve_asset_location_works() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				NEW_STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert_eq!(
+				AssetIdMultiLocation::<Test>::get(LOCAL_ASSET_ID),
+				Some(NEW_STATEMINE_ASSET_MULTI_LOCATION)
+			);
+			assert_eq!(
+				AssetMultiLocationId::<Test>::get(NEW_STATEMINE_ASSET_MULTI_LOCATION),
+				Some(LOCAL_ASSET_ID)
+			);
+			assert!(AssetMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			assert!(AssetRegistry::get_asset_id(&STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveAssetLocationUpdated {
+					asset_id: LOCAL_ASSET_ID,
+					old_asset_multi_location: STATEMINE_ASSET_MULTI_LOCATION,
+					new_asset_multi_location: NEW_STATEMINE_ASSET_MULTI_LOCATION,
+					alias_expiry: None,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn alias_resolves_until_expiry() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				NEW_STATEMINE_ASSET_MULTI_LOCATION,
+				Some(10),
+			));
+
+			assert_eq!(
+				AssetRegistry::get_asset_id(&STATEMINE_ASSET_MULTI_LOCATION),
+				Some(LOCAL_ASSET_ID)
+			);
+			assert_eq!(
+				AssetRegistry::get_asset_id(&NEW_STATEMINE_ASSET_MULTI_LOCATION),
+				Some(LOCAL_ASSET_ID)
+			);
+			// the registered location is the new one
+			assert_eq!(
+				AssetRegistry::get_asset_multi_location(LOCAL_ASSET_ID),
+				Some(NEW_STATEMINE_ASSET_MULTI_LOCATION)
+			);
+
+			System::set_block_number(10);
+			assert!(AssetRegistry::get_asset_id(&STATEMINE_ASSET_MULTI_LOCATION).is_none());
+		});
+	}
+
+	#[test]
+	fn cannot_update_unregistered_asset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AssetRegistry::update_reserve_asset_location(
+					RuntimeOrigin::root(),
+					LOCAL_ASSET_ID,
+					NEW_STATEMINE_ASSET_MULTI_LOCATION,
+					None,
+				),
+				Error::<Test>::AssetIsNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_update_to_registered_location() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+
+			assert_noop!(
+				AssetRegistry::update_reserve_asset_location(
+					RuntimeOrigin::root(),
+					LOCAL_ASSET_ID,
+					STATEMINE_ASSET_MULTI_LOCATION,
+					None,
+				),
+				Error::<Test>::LocationAlreadyRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_set_expired_alias() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+
+			assert_noop!(
+				AssetRegistry::update_reserve_asset_location(
+					RuntimeOrigin::root(),
+					LOCAL_ASSET_ID,
+					NEW_STATEMINE_ASSET_MULTI_LOCATION,
+					Some(1),
+				),
+				Error::<Test>::InvalidAliasExpiry
+			);
+		});
+	}
+
+	#[test]
+	fn remove_reserve_asset_alias_works() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				NEW_STATEMINE_ASSET_MULTI_LOCATION,
+				Some(10),
+			));
+
+			// only the modifier origin may remove an alias which is still in use
+			assert_noop!(
+				AssetRegistry::remove_reserve_asset_alias(
+					RuntimeOrigin::signed(1),
+					STATEMINE_ASSET_MULTI_LOCATION
+				),
+				BadOrigin
+			);
+
+			// anyone can clean up an expired alias
+			System::set_block_number(10);
+			assert_ok!(AssetRegistry::remove_reserve_asset_alias(
+				RuntimeOrigin::signed(1),
+				STATEMINE_ASSET_MULTI_LOCATION
+			));
+			assert!(
+				AssetAliasMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none()
+			);
+
+			assert_noop!(
+				AssetRegistry::remove_reserve_asset_alias(
+					RuntimeOrigin::root(),
+					STATEMINE_ASSET_MULTI_LOCATION
+				),
+				Error::<Test>::AliasNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn new_alias_replaces_previous_alias() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				NEW_STATEMINE_ASSET_MULTI_LOCATION,
+				Some(10),
+			));
+
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				Some(10),
+			));
+
+			assert!(
+				AssetAliasMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none()
+			);
+			assert_eq!(
+				AssetAliasMultiLocationId::<Test>::get(NEW_STATEMINE_ASSET_MULTI_LOCATION),
+				Some((LOCAL_ASSET_ID, 10))
+			);
+			assert_eq!(
+				AssetIdAliasMultiLocation::<Test>::get(LOCAL_ASSET_ID),
+				Some(NEW_STATEMINE_ASSET_MULTI_LOCATION)
+			);
+			assert_ok!(AssetRegistry::do_try_state());
+		});
+	}
+
+	#[test]
+	fn unregistering_removes_alias() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				NEW_STATEMINE_ASSET_MULTI_LOCATION,
+				Some(10),
+			));
+
+			assert_ok!(AssetRegistry::force_unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID
+			));
+
+			assert!(
+				AssetAliasMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none()
+			);
+			assert!(AssetIdAliasMultiLocation::<Test>::get(LOCAL_ASSET_ID).is_none());
+			assert_ok!(AssetRegistry::do_try_state());
+		});
+	}
+
+	#[test]
+	fn alias_stops_resolving_once_asset_moves_again() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				NEW_STATEMINE_ASSET_MULTI_LOCATION,
+				Some(10),
+			));
+
+			// moving away from the new location does not keep forwarding the first one
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				MOVED_STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert!(AssetRegistry::get_asset_id(&STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			assert!(AssetRegistry::get_asset_id(&NEW_STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			assert_ok!(AssetRegistry::do_try_state());
+		});
+	}
+
+	#[test]
+	fn alias_does_not_resolve_to_reregistered_asset() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::update_reserve_asset_location(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				NEW_STATEMINE_ASSET_MULTI_LOCATION,
+				Some(10),
+			));
+
+			burn_local_asset_supply();
+			assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(0), LOCAL_ASSET_ID));
+			assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), LOCAL_ASSET_ID));
+			assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), LOCAL_ASSET_ID));
+			assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), LOCAL_ASSET_ID));
+			assert!(
+				AssetAliasMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none()
+			);
+
+			// the id is reused for an asset of another reserve
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), LOCAL_ASSET_ID, 0, true, 1));
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				MOVED_STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert!(AssetRegistry::get_asset_id(&STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			assert_ok!(AssetRegistry::do_try_state());
+		});
+	}
+}
+
+mod reserve_chain_origin {
+	use super::*;
+	use frame_support::error::BadOrigin;
+
+	const SIBLING_ASSET_MULTI_LOCATION: MultiLocation = MultiLocation {
+		parents: 1,
+		interior: X3(Parachain(2000), PalletInstance(50), GeneralIndex(1)),
+	};
+
+	fn create_derivative() {
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			FIRST_DERIVATIVE_ASSET_ID,
+			DEPOSITOR,
+			true,
+			1
+		));
+	}
+
+	#[test]
+	fn reserve_chain_registers_own_asset() {
+		new_test_ext().execute_with(|| {
+			create_derivative();
+
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+				FIRST_DERIVATIVE_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert_eq!(
+				AssetIdMultiLocation::<Test>::get(FIRST_DERIVATIVE_ASSET_ID),
+				Some(STATEMINE_ASSET_MULTI_LOCATION)
+			);
+		});
+	}
+
+	#[test]
+	fn reserve_chain_cannot_register_local_asset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AssetRegistry::register_reserve_asset(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+					STATEMINE_ASSET_MULTI_LOCATION,
+					None,
+				),
+				Error::<Test>::NotDerivativeAssetId
+			);
+		});
+	}
+
+	#[test]
+	fn reserve_chain_cannot_register_derivative_with_supply() {
+		new_test_ext().execute_with(|| {
+			create_derivative();
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(DEPOSITOR),
+				FIRST_DERIVATIVE_ASSET_ID,
+				1,
+				10
+			));
+
+			assert_noop!(
+				AssetRegistry::register_reserve_asset(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					FIRST_DERIVATIVE_ASSET_ID,
+					STATEMINE_ASSET_MULTI_LOCATION,
+					None,
+				),
+				Error::<Test>::AssetHasSupply
+			);
+		});
+	}
+
+	#[test]
+	fn reserve_chain_cannot_register_foreign_asset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AssetRegistry::register_reserve_asset(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+					SIBLING_ASSET_MULTI_LOCATION,
+					None,
+				),
+				Error::<Test>::WrongMultiLocation
+			);
+			assert_noop!(
+				AssetRegistry::register_reserve_asset(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+					MultiLocation::parent(),
+					None,
+				),
+				Error::<Test>::WrongMultiLocation
+			);
+		});
+	}
+
+	#[test]
+	fn reserve_chain_cannot_modify_foreign_asset() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				SIBLING_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert_noop!(
+				AssetRegistry::unregister_reserve_asset(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+				),
+				BadOrigin
+			);
+			assert_noop!(
+				AssetRegistry::update_reserve_asset_location(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+					STATEMINE_ASSET_MULTI_LOCATION,
+					None,
+				),
+				BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn reserve_chain_cannot_move_asset_out_of_its_prefix() {
+		new_test_ext().execute_with(|| {
+			create_derivative();
+
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+				FIRST_DERIVATIVE_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert_noop!(
+				AssetRegistry::update_reserve_asset_location(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					FIRST_DERIVATIVE_ASSET_ID,
+					SIBLING_ASSET_MULTI_LOCATION,
+					None,
+				),
+				Error::<Test>::WrongMultiLocation
+			);
+			assert_ok!(AssetRegistry::unregister_reserve_asset(
+				RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+				FIRST_DERIVATIVE_ASSET_ID,
+			));
+		});
+	}
+}
+
+mod asset_trust {
+	use super::*;
+	use frame_support::{error::BadOrigin, traits::ContainsPair};
+	use xcm_primitives::{AssetTrust, RegisteredReserves, RegisteredTeleporters};
+
+	fn statemine() -> MultiLocation {
+		MultiLocation::new(1, X1(Parachain(StatemineParaIdInfo::get())))
+	}
+
+	fn statemine_asset() -> MultiAsset {
+		(STATEMINE_ASSET_MULTI_LOCATION, 1_000u128).into()
+	}
+
+	fn register_statemine_asset() {
+		assert_ok!(AssetRegistry::register_reserve_asset(
+			RuntimeOrigin::root(),
+			LOCAL_ASSET_ID,
+			STATEMINE_ASSET_MULTI_LOCATION,
+			None,
+		));
+	}
+
+	#[test]
+	fn registered_assets_are_trusted_from_their_reserve() {
+		new_test_ext().execute_with(|| {
+			assert!(!RegisteredReserves::<AssetRegistry>::contains(
+				&statemine_asset(),
+				&statemine()
+			));
+
+			register_statemine_asset();
+
+			assert_eq!(AssetIdTrust::<Test>::get(LOCAL_ASSET_ID), AssetTrust::Reserve);
+			assert!(RegisteredReserves::<AssetRegistry>::contains(
+				&statemine_asset(),
+				&statemine()
+			));
+			assert!(!RegisteredReserves::<AssetRegistry>::contains(
+				&statemine_asset(),
+				&MultiLocation::parent()
+			));
+			assert!(!RegisteredTeleporters::<AssetRegistry>::contains(
+				&statemine_asset(),
+				&statemine()
+			));
+		});
+	}
+
+	#[test]
+	fn set_asset_trust_works() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+
+			assert_ok!(AssetRegistry::set_asset_trust(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				AssetTrust::Teleport
+			));
+
+			assert!(RegisteredTeleporters::<AssetRegistry>::contains(
+				&statemine_asset(),
+				&statemine()
+			));
+			assert!(!RegisteredReserves::<AssetRegistry>::contains(
+				&statemine_asset(),
+				&statemine()
+			));
+			System::assert_last_event(
+				crate::Event::<Test>::AssetTrustUpdated {
+					asset_id: LOCAL_ASSET_ID,
+					trust: AssetTrust::Teleport,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn bridged_assets_are_trusted_from_their_bridge_only() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			let bridge = MultiLocation::new(1, X1(Parachain(1002)));
+
+			assert_ok!(AssetRegistry::set_asset_trust(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				AssetTrust::Bridged(bridge)
+			));
+
+			assert!(RegisteredReserves::<AssetRegistry>::contains(&statemine_asset(), &bridge));
+			assert!(!RegisteredReserves::<AssetRegistry>::contains(
+				&statemine_asset(),
+				&statemine()
+			));
+		});
+	}
+
+	#[test]
+	fn set_asset_trust_requires_unrestricted_origin() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+
+			assert_noop!(
+				AssetRegistry::set_asset_trust(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+					AssetTrust::Teleport
+				),
+				BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn set_asset_trust_fails_for_unregistered_asset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AssetRegistry::set_asset_trust(
+					RuntimeOrigin::root(),
+					LOCAL_ASSET_ID,
+					AssetTrust::Teleport
+				),
+				Error::<Test>::AssetIsNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn unregister_reserve_asset_removes_trust() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::set_asset_trust(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				AssetTrust::Teleport
+			));
+			burn_local_asset_supply();
+
+			assert_ok!(AssetRegistry::unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID
+			));
+
+			assert!(!AssetIdTrust::<Test>::contains_key(LOCAL_ASSET_ID));
+			assert!(!RegisteredTeleporters::<AssetRegistry>::contains(
+				&statemine_asset(),
+				&statemine()
+			));
+		});
+	}
+}
+
+mod register_reserve_asset_with_deposit {
+	use super::*;
+	use crate::{NextDerivativeAssetId, PendingMultiLocationId, PendingRegistrations};
+	use frame_support::{
+		error::BadOrigin,
+		traits::{
+			fungible::InspectHold,
+			fungibles::{metadata::Inspect as InspectMetadata, roles::Inspect as InspectRoles},
+		},
+	};
+	use xcm_primitives::AssetMultiLocationGetter;
+
+	fn metadata() -> crate::AssetMetadataOf<Test> {
+		AssetMetadata {
+			name: b"Reserve Token".to_vec().try_into().unwrap(),
+			symbol: b"RES".to_vec().try_into().unwrap(),
+			decimals: 12,
+			reserve_decimals: 12,
+		}
+	}
+
+	fn request_registration(asset_multi_location: MultiLocation) {
+		assert_ok!(AssetRegistry::register_reserve_asset_with_deposit(
+			RuntimeOrigin::signed(DEPOSITOR),
+			asset_multi_location,
+			metadata(),
+			1,
+		));
+	}
+
+	#[test]
+	fn register_reserve_asset_with_deposit_works() {
+		new_test_ext().execute_with(|| {
+			request_registration(STATEMINE_ASSET_MULTI_LOCATION);
+
+			assert_eq!(Balances::total_balance_on_hold(&DEPOSITOR), REGISTRATION_DEPOSIT);
+			assert_eq!(Assets::owner(PENDING_ASSET_ID), Some(AssetRegistry::account_id()));
+			assert_eq!(
+				PendingRegistrations::<Test>::get(PENDING_ASSET_ID).map(|p| p.asset_multi_location),
+				Some(STATEMINE_ASSET_MULTI_LOCATION)
+			);
+			assert_eq!(
+				PendingMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION),
+				Some(PENDING_ASSET_ID)
+			);
+			assert_eq!(NextDerivativeAssetId::<Test>::get(), Some(PENDING_ASSET_ID + 1));
+			// pending assets are not matched by the transactor
+			assert!(AssetRegistry::get_asset_id(&STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveAssetRegistrationRequested {
+					asset_id: PENDING_ASSET_ID,
+					asset_multi_location: STATEMINE_ASSET_MULTI_LOCATION,
+					depositor: DEPOSITOR,
+					deposit: REGISTRATION_DEPOSIT,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn register_reserve_asset_with_deposit_fails_for_pending_location() {
+		new_test_ext().execute_with(|| {
+			request_registration(STATEMINE_ASSET_MULTI_LOCATION);
+
+			assert_noop!(
+				AssetRegistry::register_reserve_asset_with_deposit(
+					RuntimeOrigin::signed(DEPOSITOR),
+					STATEMINE_ASSET_MULTI_LOCATION,
+					metadata(),
+					1,
+				),
+				Error::<Test>::LocationIsPending
+			);
+		});
+	}
+
+	#[test]
+	fn register_reserve_asset_with_deposit_fails_for_registered_location() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert_noop!(
+				AssetRegistry::register_reserve_asset_with_deposit(
+					RuntimeOrigin::signed(DEPOSITOR),
+					STATEMINE_ASSET_MULTI_LOCATION,
+					metadata(),
+					1,
+				),
+				Error::<Test>::LocationAlreadyRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn register_reserve_asset_with_deposit_fails_for_used_location() {
+		new_test_ext().execute_with(|| {
+			for (location, error) in occupy_locations() {
+				assert_noop!(
+					AssetRegistry::register_reserve_asset_with_deposit(
+						RuntimeOrigin::signed(DEPOSITOR),
+						location,
+						metadata(),
+						1,
+					),
+					error
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn approve_reserve_asset_registration_works() {
+		new_test_ext().execute_with(|| {
+			request_registration(STATEMINE_ASSET_MULTI_LOCATION);
+
+			assert_ok!(AssetRegistry::approve_reserve_asset_registration(
+				RuntimeOrigin::root(),
+				PENDING_ASSET_ID
+			));
+
+			assert!(!PendingRegistrations::<Test>::contains_key(PENDING_ASSET_ID));
+			assert_eq!(Balances::total_balance_on_hold(&DEPOSITOR), 0);
+			assert_eq!(Balances::free_balance(DEPOSITOR), 1_000);
+			assert_eq!(
+				AssetRegistry::get_asset_id(&STATEMINE_ASSET_MULTI_LOCATION),
+				Some(PENDING_ASSET_ID)
+			);
+			assert_eq!(AssetIdMetadata::<Test>::get(PENDING_ASSET_ID), Some(metadata()));
+			assert!(PendingMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			// the requested metadata is set on the derivative
+			assert_eq!(Assets::name(PENDING_ASSET_ID), b"Reserve Token".to_vec());
+			assert_eq!(Assets::symbol(PENDING_ASSET_ID), b"RES".to_vec());
+			assert_eq!(Assets::decimals(PENDING_ASSET_ID), 12);
+		});
+	}
+
+	#[test]
+	fn reject_reserve_asset_registration_slashes_deposit() {
+		new_test_ext().execute_with(|| {
+			request_registration(STATEMINE_ASSET_MULTI_LOCATION);
+
+			assert_ok!(AssetRegistry::reject_reserve_asset_registration(
+				RuntimeOrigin::root(),
+				PENDING_ASSET_ID
+			));
+
+			assert!(!PendingRegistrations::<Test>::contains_key(PENDING_ASSET_ID));
+			assert_eq!(Balances::total_balance_on_hold(&DEPOSITOR), 0);
+			assert_eq!(Balances::free_balance(DEPOSITOR), 1_000 - REGISTRATION_DEPOSIT);
+			assert!(PendingMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			// the derivative is being destroyed, anyone can finish it
+			assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), PENDING_ASSET_ID));
+			assert!(AssetRegistry::get_asset_id(&STATEMINE_ASSET_MULTI_LOCATION).is_none());
+		});
+	}
+
+	#[test]
+	fn refund_reserve_asset_registration_releases_deposit() {
+		new_test_ext().execute_with(|| {
+			request_registration(STATEMINE_ASSET_MULTI_LOCATION);
+
+			assert_ok!(AssetRegistry::refund_reserve_asset_registration(
+				RuntimeOrigin::root(),
+				PENDING_ASSET_ID
+			));
+
+			assert!(!PendingRegistrations::<Test>::contains_key(PENDING_ASSET_ID));
+			assert_eq!(Balances::total_balance_on_hold(&DEPOSITOR), 0);
+			assert_eq!(Balances::free_balance(DEPOSITOR), 1_000);
+			assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), PENDING_ASSET_ID));
+		});
+	}
+
+	#[test]
+	fn pending_asset_cannot_be_registered_directly() {
+		new_test_ext().execute_with(|| {
+			request_registration(STATEMINE_ASSET_MULTI_LOCATION);
+
+			assert_noop!(
+				AssetRegistry::register_reserve_asset(
+					RuntimeOrigin::root(),
+					PENDING_ASSET_ID,
+					STATEMINE_ASSET_MULTI_LOCATION,
+					None,
+				),
+				Error::<Test>::AssetIsPending
+			);
+		});
+	}
+
+	#[test]
+	fn reserve_chain_reviews_only_its_own_registrations() {
+		new_test_ext().execute_with(|| {
+			request_registration(MultiLocation::new(1, X2(Parachain(2000), PalletInstance(50))));
+
+			assert_noop!(
+				AssetRegistry::approve_reserve_asset_registration(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					PENDING_ASSET_ID
+				),
+				BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn review_fails_without_pending_registration() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AssetRegistry::approve_reserve_asset_registration(
+					RuntimeOrigin::root(),
+					PENDING_ASSET_ID
+				),
+				Error::<Test>::RegistrationNotPending
+			);
+		});
+	}
+}
+
+mod create_and_register_reserve_asset {
+	use super::*;
+	use crate::NextDerivativeAssetId;
+	use frame_support::{
+		error::BadOrigin,
+		traits::fungibles::{metadata::Inspect as InspectMetadata, roles::Inspect as InspectRoles},
+	};
+
+	fn metadata() -> crate::AssetMetadataOf<Test> {
+		AssetMetadata {
+			name: b"Derivative Token".to_vec().try_into().unwrap(),
+			symbol: b"DRV".to_vec().try_into().unwrap(),
+			decimals: 10,
+			reserve_decimals: 12,
+		}
+	}
+
+	fn location(index: u128) -> MultiLocation {
+		MultiLocation {
+			parents: 1,
+			interior: X3(
+				Parachain(StatemineParaIdInfo::get()),
+				PalletInstance(StatemineAssetsInstanceInfo::get()),
+				GeneralIndex(index),
+			),
+		}
+	}
+
+	#[test]
+	fn create_and_register_reserve_asset_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::create_and_register_reserve_asset(
+				RuntimeOrigin::root(),
+				STATEMINE_ASSET_MULTI_LOCATION,
+				1,
+				true,
+				metadata(),
+			));
+
+			assert_eq!(Assets::owner(FIRST_DERIVATIVE_ASSET_ID), Some(AssetRegistry::account_id()));
+			assert_eq!(Assets::name(FIRST_DERIVATIVE_ASSET_ID), b"Derivative Token".to_vec());
+			assert_eq!(Assets::symbol(FIRST_DERIVATIVE_ASSET_ID), b"DRV".to_vec());
+			assert_eq!(Assets::decimals(FIRST_DERIVATIVE_ASSET_ID), 10);
+			assert_eq!(
+				AssetIdMultiLocation::<Test>::get(FIRST_DERIVATIVE_ASSET_ID),
+				Some(STATEMINE_ASSET_MULTI_LOCATION)
+			);
+			assert_eq!(
+				AssetMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION),
+				Some(FIRST_DERIVATIVE_ASSET_ID)
+			);
+			assert_eq!(AssetIdMetadata::<Test>::get(FIRST_DERIVATIVE_ASSET_ID), Some(metadata()));
+			assert_eq!(NextDerivativeAssetId::<Test>::get(), Some(FIRST_DERIVATIVE_ASSET_ID + 1));
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveAssetRegistered {
+					asset_id: FIRST_DERIVATIVE_ASSET_ID,
+					asset_multi_location: STATEMINE_ASSET_MULTI_LOCATION,
+					metadata: metadata(),
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn create_and_register_reserve_asset_skips_existing_ids() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				FIRST_DERIVATIVE_ASSET_ID,
+				DEPOSITOR,
+				true,
+				1
+			));
+
+			assert_ok!(AssetRegistry::create_and_register_reserve_asset(
+				RuntimeOrigin::root(),
+				STATEMINE_ASSET_MULTI_LOCATION,
+				1,
+				true,
+				metadata(),
+			));
+
+			assert_eq!(
+				AssetMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION),
+				Some(FIRST_DERIVATIVE_ASSET_ID + 1)
+			);
+			// the pre-existing asset is left untouched
+			assert_eq!(Assets::owner(FIRST_DERIVATIVE_ASSET_ID), Some(DEPOSITOR));
+		});
+	}
+
+	#[test]
+	fn create_and_register_reserve_asset_fails_when_range_is_exhausted() {
+		new_test_ext().execute_with(|| {
+			for index in 1..=3 {
+				assert_ok!(AssetRegistry::create_and_register_reserve_asset(
+					RuntimeOrigin::root(),
+					location(index),
+					1,
+					true,
+					metadata(),
+				));
+			}
+
+			assert_noop!(
+				AssetRegistry::create_and_register_reserve_asset(
+					RuntimeOrigin::root(),
+					location(4),
+					1,
+					true,
+					metadata(),
+				),
+				Error::<Test>::NoDerivativeAssetIdAvailable
+			);
+		});
+	}
+
+	#[test]
+	fn create_and_register_reserve_asset_fails_for_registered_location() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			assert_noop!(
+				AssetRegistry::create_and_register_reserve_asset(
+					RuntimeOrigin::root(),
+					STATEMINE_ASSET_MULTI_LOCATION,
+					1,
+					true,
+					metadata(),
+				),
+				Error::<Test>::LocationAlreadyRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn create_and_register_reserve_asset_fails_for_used_location() {
+		new_test_ext().execute_with(|| {
+			for (location, error) in occupy_locations() {
+				assert_noop!(
+					AssetRegistry::create_and_register_reserve_asset(
+						RuntimeOrigin::root(),
+						location,
+						1,
+						true,
+						metadata(),
+					),
+					error
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn reserve_chain_creates_only_own_assets() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::create_and_register_reserve_asset(
+				RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+				STATEMINE_ASSET_MULTI_LOCATION,
+				1,
+				false,
+				metadata(),
+			));
+
+			assert_noop!(
+				AssetRegistry::create_and_register_reserve_asset(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					MultiLocation::new(1, X3(Parachain(2000), PalletInstance(50), GeneralIndex(1))),
+					1,
+					false,
+					metadata(),
+				),
+				Error::<Test>::WrongMultiLocation
+			);
+			assert_noop!(
+				AssetRegistry::create_and_register_reserve_asset(
+					RuntimeOrigin::signed(DEPOSITOR),
+					location(2),
+					1,
+					false,
+					metadata(),
+				),
+				BadOrigin
+			);
+		});
+	}
+}
+
+mod units_per_second {
+	use super::*;
+	use crate::AssetIdUnitsPerSecond;
+	use frame_support::{
+		error::BadOrigin,
+		weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+	};
+	use xcm_executor::{traits::WeightTrader, Assets};
+	use xcm_primitives::{UnitsPerSecondGetter, UsingRegisteredUnitsPerSecond};
+
+	// two units per unit of ref time
+	const UNITS_PER_SECOND: u128 = 2 * WEIGHT_REF_TIME_PER_SECOND as u128;
+
+	type Trader = UsingRegisteredUnitsPerSecond<AssetRegistry, ()>;
+
+	fn register_statemine_asset() {
+		assert_ok!(AssetRegistry::register_reserve_asset(
+			RuntimeOrigin::root(),
+			LOCAL_ASSET_ID,
+			STATEMINE_ASSET_MULTI_LOCATION,
+			None,
+		));
+	}
+
+	fn payment(amount: u128) -> Assets {
+		MultiAsset::from((STATEMINE_ASSET_MULTI_LOCATION, amount)).into()
+	}
+
+	#[test]
+	fn set_asset_units_per_second_works() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_eq!(AssetRegistry::get_units_per_second(&STATEMINE_ASSET_MULTI_LOCATION), None);
+
+			assert_ok!(AssetRegistry::set_asset_units_per_second(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				Some(UNITS_PER_SECOND)
+			));
+
+			assert_eq!(
+				AssetRegistry::get_units_per_second(&STATEMINE_ASSET_MULTI_LOCATION),
+				Some(UNITS_PER_SECOND)
+			);
+			System::assert_last_event(
+				crate::Event::<Test>::AssetUnitsPerSecondUpdated {
+					asset_id: LOCAL_ASSET_ID,
+					units_per_second: Some(UNITS_PER_SECOND),
+				}
+				.into(),
+			);
+
+			assert_ok!(AssetRegistry::set_asset_units_per_second(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				None
+			));
+			assert!(!AssetIdUnitsPerSecond::<Test>::contains_key(LOCAL_ASSET_ID));
+		});
+	}
+
+	#[test]
+	fn set_asset_units_per_second_fails() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AssetRegistry::set_asset_units_per_second(
+					RuntimeOrigin::root(),
+					LOCAL_ASSET_ID,
+					Some(UNITS_PER_SECOND)
+				),
+				Error::<Test>::AssetIsNotRegistered
+			);
+
+			register_statemine_asset();
+			assert_noop!(
+				AssetRegistry::set_asset_units_per_second(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+					Some(UNITS_PER_SECOND)
+				),
+				BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn unregister_reserve_asset_removes_units_per_second() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::set_asset_units_per_second(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				Some(UNITS_PER_SECOND)
+			));
+			burn_local_asset_supply();
+
+			assert_ok!(AssetRegistry::unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID
+			));
+
+			assert!(!AssetIdUnitsPerSecond::<Test>::contains_key(LOCAL_ASSET_ID));
+		});
+	}
+
+	#[test]
+	fn trader_charges_and_refunds_priced_assets() {
+		new_test_ext().execute_with(|| {
+			let context = XcmContext::with_message_id([0; 32]);
+			register_statemine_asset();
+
+			let mut trader = Trader::new();
+			assert_eq!(
+				trader.buy_weight(Weight::from_parts(1_000, 0), payment(10_000), &context),
+				Err(XcmError::AssetNotFound)
+			);
+
+			assert_ok!(AssetRegistry::set_asset_units_per_second(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				Some(UNITS_PER_SECOND)
+			));
+
+			let unused = trader
+				.buy_weight(Weight::from_parts(1_000, 0), payment(10_000), &context)
+				.unwrap();
+			assert_eq!(unused, payment(8_000));
+			assert_eq!(
+				trader.buy_weight(Weight::from_parts(10_000, 0), payment(10_000), &context),
+				Err(XcmError::TooExpensive)
+			);
+
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(400, 0), &context),
+				Some((STATEMINE_ASSET_MULTI_LOCATION, 800u128).into())
+			);
+			// refunds are capped by the weight bought
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(1_000, 0), &context),
+				Some((STATEMINE_ASSET_MULTI_LOCATION, 1_200u128).into())
+			);
+			assert_eq!(trader.refund_weight(Weight::from_parts(1_000, 0), &context), None);
+		});
+	}
+}
+
+mod flow_limits {
+	use super::*;
+	use crate::{AssetIdFlow, AssetIdStatus, FlowDirection, FlowLimits};
+	use frame_support::error::BadOrigin;
+	use xcm_builder::JustTry;
+	use xcm_executor::traits::{Error as MatchError, MatchesFungibles};
+	use xcm_primitives::{
+		AsAssetMultiLocation, AssetFlowLimiter, AssetStatus, ConvertedRegisteredAssetId,
+	};
+
+	type Matcher = ConvertedRegisteredAssetId<
+		u32,
+		u64,
+		AsAssetMultiLocation<u32, AssetRegistry>,
+		JustTry,
+		AssetRegistry,
+		AssetRegistry,
+	>;
+
+	fn statemine_asset() -> MultiAsset {
+		(STATEMINE_ASSET_MULTI_LOCATION, 10u128).into()
+	}
+
+	fn register_statemine_asset(limits: FlowLimits) {
+		assert_ok!(AssetRegistry::register_reserve_asset(
+			RuntimeOrigin::root(),
+			LOCAL_ASSET_ID,
+			STATEMINE_ASSET_MULTI_LOCATION,
+			None,
+		));
+		assert_ok!(AssetRegistry::set_asset_flow_limits(
+			RuntimeOrigin::root(),
+			LOCAL_ASSET_ID,
+			Some(limits)
+		));
+	}
+
+	#[test]
+	fn frozen_assets_are_not_matched() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset(FlowLimits {
+				inbound: None,
+				outbound: None,
+				quarantine: false,
+			});
+			assert_eq!(Matcher::matches_fungibles(&statemine_asset()), Ok((LOCAL_ASSET_ID, 10)));
+
+			assert_ok!(AssetRegistry::set_asset_status(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				AssetStatus::Frozen
+			));
+			System::assert_last_event(
+				crate::Event::<Test>::AssetStatusUpdated {
+					asset_id: LOCAL_ASSET_ID,
+					status: AssetStatus::Frozen,
+				}
+				.into(),
+			);
+			assert_eq!(
+				Matcher::matches_fungibles(&statemine_asset()),
+				Err(MatchError::AssetNotHandled)
+			);
+
+			assert_ok!(AssetRegistry::set_asset_status(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				AssetStatus::Active
+			));
+			assert_eq!(Matcher::matches_fungibles(&statemine_asset()), Ok((LOCAL_ASSET_ID, 10)));
+		});
+	}
+
+	#[test]
+	fn setting_status_and_limits_requires_unrestricted_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AssetRegistry::set_asset_status(
+					RuntimeOrigin::root(),
+					LOCAL_ASSET_ID,
+					AssetStatus::Frozen
+				),
+				Error::<Test>::AssetIsNotRegistered
+			);
+
+			register_statemine_asset(FlowLimits {
+				inbound: None,
+				outbound: None,
+				quarantine: false,
+			});
+			assert_noop!(
+				AssetRegistry::set_asset_status(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+					AssetStatus::Active
+				),
+				BadOrigin
+			);
+			assert_noop!(
+				AssetRegistry::set_asset_flow_limits(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID,
+					None
+				),
+				BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn inbound_limit_applies_per_window() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset(FlowLimits {
+				inbound: Some(100),
+				outbound: None,
+				quarantine: false,
+			});
+
+			assert_ok!(AssetRegistry::note_inbound(&STATEMINE_ASSET_MULTI_LOCATION, 60));
+			assert_eq!(
+				AssetRegistry::note_inbound(&STATEMINE_ASSET_MULTI_LOCATION, 50),
+				Err(XcmError::NotDepositable)
+			);
+			System::assert_last_event(
+				crate::Event::<Test>::AssetFlowLimitExceeded {
+					asset_id: LOCAL_ASSET_ID,
+					direction: FlowDirection::Inbound,
+					amount: 50,
+				}
+				.into(),
+			);
+			assert_eq!(AssetIdStatus::<Test>::get(LOCAL_ASSET_ID), AssetStatus::Active);
+			assert_ok!(AssetRegistry::note_inbound(&STATEMINE_ASSET_MULTI_LOCATION, 40));
+			assert_ok!(AssetRegistry::note_outbound(&STATEMINE_ASSET_MULTI_LOCATION, 1_000));
+
+			System::set_block_number(1 + FLOW_LIMIT_WINDOW);
+			assert_ok!(AssetRegistry::note_inbound(&STATEMINE_ASSET_MULTI_LOCATION, 100));
+			assert_eq!(
+				AssetIdFlow::<Test>::get(LOCAL_ASSET_ID).window_start,
+				1 + FLOW_LIMIT_WINDOW
+			);
+		});
+	}
+
+	#[test]
+	fn exceeding_a_limit_quarantines_the_asset() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset(FlowLimits {
+				inbound: None,
+				outbound: Some(100),
+				quarantine: true,
+			});
+
+			assert_eq!(
+				AssetRegistry::note_outbound(&STATEMINE_ASSET_MULTI_LOCATION, 150),
+				Err(XcmError::NotWithdrawable)
+			);
+			System::assert_last_event(
+				crate::Event::<Test>::AssetStatusUpdated {
+					asset_id: LOCAL_ASSET_ID,
+					status: AssetStatus::Quarantined,
+				}
+				.into(),
+			);
+			assert_eq!(
+				Matcher::matches_fungibles(&statemine_asset()),
+				Err(MatchError::AssetNotHandled)
+			);
+
+			// governance releases the asset
+			assert_ok!(AssetRegistry::set_asset_status(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				AssetStatus::Active
+			));
+			assert_eq!(Matcher::matches_fungibles(&statemine_asset()), Ok((LOCAL_ASSET_ID, 10)));
+		});
+	}
+
+	#[test]
+	fn unlimited_assets_are_not_accounted() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::note_inbound(&STATEMINE_ASSET_MULTI_LOCATION, u128::MAX));
+
+			register_statemine_asset(FlowLimits {
+				inbound: Some(100),
+				outbound: None,
+				quarantine: false,
+			});
+			assert_ok!(AssetRegistry::set_asset_flow_limits(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				None
+			));
+
+			assert_ok!(AssetRegistry::note_inbound(&STATEMINE_ASSET_MULTI_LOCATION, u128::MAX));
+			assert!(!AssetIdFlow::<Test>::contains_key(LOCAL_ASSET_ID));
+		});
+	}
+}
+
+mod genesis {
+	use super::*;
+	use frame_support::traits::{
+		fungibles::{metadata::Inspect as InspectMetadata, roles::Inspect as InspectRoles},
+		BuildGenesisConfig,
+	};
+
+	const GENESIS_ASSET_ID: u32 = 30;
+
+	fn genesis_config(assets: Vec<(u32, MultiLocation)>) -> crate::GenesisConfig<Test> {
+		crate::GenesisConfig {
+			assets: assets
+				.into_iter()
+				.map(|(asset_id, location)| {
+					(asset_id, location, true, 1, b"Genesis Token".to_vec(), b"GEN".to_vec(), 6, 12)
+				})
+				.collect(),
+		}
+	}
+
+	#[test]
+	fn genesis_creates_and_registers_assets() {
+		new_test_ext().execute_with(|| {
+			genesis_config(vec![(GENESIS_ASSET_ID, STATEMINE_ASSET_MULTI_LOCATION)]).build();
+
+			assert_eq!(Assets::owner(GENESIS_ASSET_ID), Some(AssetRegistry::account_id()));
+			assert_eq!(Assets::symbol(GENESIS_ASSET_ID), b"GEN".to_vec());
+			assert_eq!(Assets::decimals(GENESIS_ASSET_ID), 6);
+			assert_eq!(
+				AssetIdMultiLocation::<Test>::get(GENESIS_ASSET_ID),
+				Some(STATEMINE_ASSET_MULTI_LOCATION)
+			);
+			assert_eq!(
+				AssetMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION),
+				Some(GENESIS_ASSET_ID)
+			);
+			assert_eq!(
+				AssetIdMetadata::<Test>::get(GENESIS_ASSET_ID).map(|m| m.reserve_decimals),
+				Some(12)
+			);
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "Location registered twice in genesis.")]
+	fn genesis_rejects_duplicate_locations() {
+		new_test_ext().execute_with(|| {
+			genesis_config(vec![
+				(GENESIS_ASSET_ID, STATEMINE_ASSET_MULTI_LOCATION),
+				(GENESIS_ASSET_ID + 1, STATEMINE_ASSET_MULTI_LOCATION),
+			])
+			.build();
+		});
+	}
+}
+
+mod try_state {
+	use super::*;
+
+	fn register_statemine_asset() {
+		assert_ok!(AssetRegistry::register_reserve_asset(
+			RuntimeOrigin::root(),
+			LOCAL_ASSET_ID,
+			STATEMINE_ASSET_MULTI_LOCATION,
+			None,
+		));
+	}
+
+	#[test]
+	fn try_state_holds_for_registered_assets() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::do_try_state());
+
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::do_try_state());
+
+			assert_ok!(AssetRegistry::unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID
+			));
+			assert_ok!(AssetRegistry::do_try_state());
+		});
+	}
+
+	#[test]
+	fn try_state_detects_broken_bijection() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			AssetMultiLocationId::<Test>::insert(MultiLocation::parent(), LOCAL_ASSET_ID);
+			assert!(AssetRegistry::do_try_state().is_err());
+		});
+
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			AssetMultiLocationId::<Test>::insert(STATEMINE_ASSET_MULTI_LOCATION, PENDING_ASSET_ID);
+			assert!(AssetRegistry::do_try_state().is_err());
+		});
+	}
+
+	#[test]
+	fn try_state_detects_missing_assets() {
+		new_test_ext().execute_with(|| {
+			AssetIdMultiLocation::<Test>::insert(PENDING_ASSET_ID, STATEMINE_ASSET_MULTI_LOCATION);
+			AssetMultiLocationId::<Test>::insert(STATEMINE_ASSET_MULTI_LOCATION, PENDING_ASSET_ID);
+			assert!(AssetRegistry::do_try_state().is_err());
+		});
+	}
+
+	#[test]
+	fn destroying_an_asset_unregisters_it() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+
+			assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(0), LOCAL_ASSET_ID));
+			assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), LOCAL_ASSET_ID));
+			assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), LOCAL_ASSET_ID));
+			// still registered until the asset is gone
+			assert!(AssetIdMultiLocation::<Test>::contains_key(LOCAL_ASSET_ID));
+
+			assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), LOCAL_ASSET_ID));
+
+			assert!(!AssetIdMultiLocation::<Test>::contains_key(LOCAL_ASSET_ID));
+			assert!(!AssetMultiLocationId::<Test>::contains_key(STATEMINE_ASSET_MULTI_LOCATION));
+			assert!(!AssetIdMetadata::<Test>::contains_key(LOCAL_ASSET_ID));
+			System::assert_has_event(
+				crate::Event::<Test>::ReserveAssetUnregistered {
+					asset_id: LOCAL_ASSET_ID,
+					asset_multi_location: STATEMINE_ASSET_MULTI_LOCATION,
+				}
+				.into(),
+			);
+			assert_ok!(AssetRegistry::do_try_state());
+		});
+	}
+}
+
+mod deprecation {
+	use super::*;
+	use crate::AssetIdStatus;
+	use frame_support::error::BadOrigin;
+	use xcm_primitives::{AssetFlowLimiter, AssetStatus};
+
+	fn register_statemine_asset() {
+		assert_ok!(AssetRegistry::register_reserve_asset(
+			RuntimeOrigin::root(),
+			LOCAL_ASSET_ID,
+			STATEMINE_ASSET_MULTI_LOCATION,
+			None,
+		));
+	}
+
+	#[test]
+	fn unregistering_asset_with_supply_deprecates_it() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+
+			assert_ok!(AssetRegistry::unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID
+			));
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveAssetDeprecated {
+					asset_id: LOCAL_ASSET_ID,
+					asset_multi_location: STATEMINE_ASSET_MULTI_LOCATION,
+					supply: 100,
+				}
+				.into(),
+			);
+			assert_eq!(AssetIdStatus::<Test>::get(LOCAL_ASSET_ID), AssetStatus::Deprecated);
+			assert_eq!(
+				AssetIdMultiLocation::<Test>::get(LOCAL_ASSET_ID),
+				Some(STATEMINE_ASSET_MULTI_LOCATION)
+			);
+
+			assert_noop!(
+				AssetRegistry::unregister_reserve_asset(RuntimeOrigin::root(), LOCAL_ASSET_ID),
+				Error::<Test>::AssetIsDeprecated
+			);
+		});
+	}
+
+	#[test]
+	fn deprecated_assets_can_only_leave() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_ok!(AssetRegistry::unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID
+			));
+
+			assert_eq!(
+				AssetRegistry::note_inbound(&STATEMINE_ASSET_MULTI_LOCATION, 10),
+				Err(XcmError::NotDepositable)
+			);
+			assert_ok!(AssetRegistry::note_outbound(&STATEMINE_ASSET_MULTI_LOCATION, 10));
+		});
+	}
+
+	#[test]
+	fn deprecated_asset_is_removed_once_supply_is_gone() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_noop!(
+				AssetRegistry::remove_deprecated_reserve_asset(
+					RuntimeOrigin::signed(DEPOSITOR),
+					LOCAL_ASSET_ID
+				),
+				Error::<Test>::AssetIsNotDeprecated
+			);
+
+			assert_ok!(AssetRegistry::unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID
+			));
+			assert_noop!(
+				AssetRegistry::remove_deprecated_reserve_asset(
+					RuntimeOrigin::signed(DEPOSITOR),
+					LOCAL_ASSET_ID
+				),
+				Error::<Test>::AssetHasSupply
+			);
+
+			burn_local_asset_supply();
+			assert_ok!(AssetRegistry::remove_deprecated_reserve_asset(
+				RuntimeOrigin::signed(DEPOSITOR),
+				LOCAL_ASSET_ID
+			));
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveAssetUnregistered {
+					asset_id: LOCAL_ASSET_ID,
+					asset_multi_location: STATEMINE_ASSET_MULTI_LOCATION,
+				}
+				.into(),
+			);
+			assert!(AssetIdMultiLocation::<Test>::get(LOCAL_ASSET_ID).is_none());
+			assert!(!AssetIdStatus::<Test>::contains_key(LOCAL_ASSET_ID));
+		});
+	}
+
+	#[test]
+	fn force_unregister_ignores_supply() {
+		new_test_ext().execute_with(|| {
+			register_statemine_asset();
+			assert_noop!(
+				AssetRegistry::force_unregister_reserve_asset(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_ASSET_ID
+				),
+				BadOrigin
+			);
+
+			assert_ok!(AssetRegistry::force_unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID
+			));
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveAssetUnregistered {
+					asset_id: LOCAL_ASSET_ID,
+					asset_multi_location: STATEMINE_ASSET_MULTI_LOCATION,
+				}
+				.into(),
+			);
+			assert!(AssetMultiLocationId::<Test>::get(STATEMINE_ASSET_MULTI_LOCATION).is_none());
+			assert_noop!(
+				AssetRegistry::force_unregister_reserve_asset(
+					RuntimeOrigin::root(),
+					LOCAL_ASSET_ID
+				),
+				Error::<Test>::AssetIsNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn reserve_chain_cannot_unregister_frozen_asset() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				FIRST_DERIVATIVE_ASSET_ID,
+				DEPOSITOR,
+				true,
+				1
+			));
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+				FIRST_DERIVATIVE_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(DEPOSITOR),
+				FIRST_DERIVATIVE_ASSET_ID,
+				1,
+				10
+			));
+
+			for status in [AssetStatus::Frozen, AssetStatus::Quarantined] {
+				assert_ok!(AssetRegistry::set_asset_status(
+					RuntimeOrigin::root(),
+					FIRST_DERIVATIVE_ASSET_ID,
+					status
+				));
+				assert_noop!(
+					AssetRegistry::unregister_reserve_asset(
+						RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+						FIRST_DERIVATIVE_ASSET_ID
+					),
+					Error::<Test>::AssetIsNotActive
+				);
+			}
+
+			// governance may still deprecate it
+			assert_ok!(AssetRegistry::unregister_reserve_asset(
+				RuntimeOrigin::root(),
+				FIRST_DERIVATIVE_ASSET_ID
+			));
+			assert_eq!(
+				AssetIdStatus::<Test>::get(FIRST_DERIVATIVE_ASSET_ID),
+				AssetStatus::Deprecated
+			);
+		});
+	}
+}
+
+mod reserve_collection {
+	use super::*;
+	use crate::{CollectionIdMultiLocation, CollectionMultiLocationId};
+	use frame_support::{error::BadOrigin, traits::ContainsPair};
+	use xcm_executor::traits::{Error as MatchError, JustTry, MatchesNonFungibles};
+	use xcm_primitives::{ConvertedRegisteredCollectionId, RegisteredReserves};
+
+	const STATEMINE_COLLECTION_MULTI_LOCATION: MultiLocation = MultiLocation {
+		parents: 1,
+		interior: X3(Parachain(StatemineParaIdInfo::get()), PalletInstance(51), GeneralIndex(1)),
+	};
+
+	type Matcher = ConvertedRegisteredCollectionId<u32, u32, AssetRegistry, JustTry>;
+
+	fn register_statemine_collection() {
+		assert_ok!(AssetRegistry::register_reserve_collection(
+			RuntimeOrigin::root(),
+			LOCAL_COLLECTION_ID,
+			STATEMINE_COLLECTION_MULTI_LOCATION,
+		));
+	}
+
+	#[test]
+	fn register_reserve_collection_works() {
+		new_test_ext().execute_with(|| {
+			register_statemine_collection();
+
+			assert_eq!(
+				CollectionIdMultiLocation::<Test>::get(LOCAL_COLLECTION_ID),
+				Some(STATEMINE_COLLECTION_MULTI_LOCATION)
+			);
+			assert_eq!(
+				CollectionMultiLocationId::<Test>::get(STATEMINE_COLLECTION_MULTI_LOCATION),
+				Some(LOCAL_COLLECTION_ID)
+			);
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveCollectionRegistered {
+					collection_id: LOCAL_COLLECTION_ID,
+					collection_multi_location: STATEMINE_COLLECTION_MULTI_LOCATION,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn register_reserve_collection_fails() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AssetRegistry::register_reserve_collection(
+					RuntimeOrigin::root(),
+					LOCAL_COLLECTION_ID + 1,
+					STATEMINE_COLLECTION_MULTI_LOCATION,
+				),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			assert_noop!(
+				AssetRegistry::register_reserve_collection(
+					RuntimeOrigin::root(),
+					LOCAL_COLLECTION_ID,
+					MultiLocation::here(),
+				),
+				Error::<Test>::WrongMultiLocation
+			);
+			assert_noop!(
+				AssetRegistry::register_reserve_collection(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+					LOCAL_COLLECTION_ID,
+					MultiLocation::new(1, X3(Parachain(2000), PalletInstance(51), GeneralIndex(1))),
+				),
+				Error::<Test>::WrongMultiLocation
+			);
+
+			// the location of a fungible asset cannot hold a collection
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+			assert_noop!(
+				AssetRegistry::register_reserve_collection(
+					RuntimeOrigin::root(),
+					LOCAL_COLLECTION_ID,
+					STATEMINE_ASSET_MULTI_LOCATION,
+				),
+				Error::<Test>::LocationAlreadyRegistered
+			);
+
+			register_statemine_collection();
+			assert_noop!(
+				AssetRegistry::register_reserve_collection(
+					RuntimeOrigin::root(),
+					LOCAL_COLLECTION_ID,
+					STATEMINE_COLLECTION_MULTI_LOCATION,
+				),
+				Error::<Test>::CollectionAlreadyRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn unregister_reserve_collection_works() {
+		new_test_ext().execute_with(|| {
+			register_statemine_collection();
+
+			assert_noop!(
+				AssetRegistry::unregister_reserve_collection(
+					RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT + 1),
+					LOCAL_COLLECTION_ID
+				),
+				BadOrigin
+			);
+			assert_ok!(AssetRegistry::unregister_reserve_collection(
+				RuntimeOrigin::signed(STATEMINE_ORIGIN_ACCOUNT),
+				LOCAL_COLLECTION_ID
+			));
+
+			assert!(!CollectionIdMultiLocation::<Test>::contains_key(LOCAL_COLLECTION_ID));
+			assert!(!CollectionMultiLocationId::<Test>::contains_key(
+				STATEMINE_COLLECTION_MULTI_LOCATION
+			));
+			System::assert_last_event(
+				crate::Event::<Test>::ReserveCollectionUnregistered {
+					collection_id: LOCAL_COLLECTION_ID,
+					collection_multi_location: STATEMINE_COLLECTION_MULTI_LOCATION,
+				}
+				.into(),
+			);
+			assert_noop!(
+				AssetRegistry::unregister_reserve_collection(
+					RuntimeOrigin::root(),
+					LOCAL_COLLECTION_ID
+				),
+				Error::<Test>::CollectionIsNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn registered_collections_are_matched_and_trusted() {
+		new_test_ext().execute_with(|| {
+			let item: MultiAsset =
+				(STATEMINE_COLLECTION_MULTI_LOCATION, AssetInstance::Index(7)).into();
+			let statemine = MultiLocation::new(1, X1(Parachain(StatemineParaIdInfo::get())));
+
+			assert!(matches!(
+				Matcher::matches_nonfungibles(&item),
+				Err(MatchError::AssetNotHandled)
+			));
+			assert!(!RegisteredReserves::<AssetRegistry>::contains(&item, &statemine));
+
+			register_statemine_collection();
+
+			assert_eq!(Matcher::matches_nonfungibles(&item).ok(), Some((LOCAL_COLLECTION_ID, 7)));
+			assert!(RegisteredReserves::<AssetRegistry>::contains(&item, &statemine));
+			assert!(matches!(
+				Matcher::matches_nonfungibles(
+					&(STATEMINE_COLLECTION_MULTI_LOCATION, AssetInstance::Array4([0; 4])).into()
+				),
+				Err(MatchError::InstanceConversionFailed)
+			));
+		});
+	}
+}
+
+mod lost_assets {
+	use super::*;
+	use xcm_primitives::RecordLostAsset;
+
+	#[test]
+	fn lost_assets_are_recorded() {
+		new_test_ext().execute_with(|| {
+			let origin = MultiLocation::new(1, X1(Parachain(StatemineParaIdInfo::get())));
+			let asset_id = Concrete(STATEMINE_ASSET_MULTI_LOCATION);
+
+			AssetRegistry::record_lost_asset(&origin, &asset_id, 7);
+
+			System::assert_last_event(
+				crate::Event::<Test>::AssetLost { origin, asset_id, amount: 7 }.into(),
+			);
+		});
+	}
+}
+
+mod runtime_api {
+	use super::*;
+	use xcm::VersionedMultiLocation;
+
+	#[test]
+	fn assets_are_queried_by_versioned_location() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			let versioned_location = VersionedMultiLocation::from(STATEMINE_ASSET_MULTI_LOCATION);
+			assert_eq!(
+				AssetRegistry::asset_location(LOCAL_ASSET_ID),
+				Some(versioned_location.clone())
+			);
+			assert_eq!(AssetRegistry::asset_id(versioned_location.clone()), Some(LOCAL_ASSET_ID));
+			assert_eq!(
+				AssetRegistry::registered_assets(),
+				vec![(LOCAL_ASSET_ID, versioned_location)]
+			);
+		});
+	}
+
+	#[test]
+	fn assets_are_listed_by_reserve_chain() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::register_reserve_asset(
+				RuntimeOrigin::root(),
+				LOCAL_ASSET_ID,
+				STATEMINE_ASSET_MULTI_LOCATION,
+				None,
+			));
+
+			let statemine = MultiLocation::new(1, X1(Parachain(StatemineParaIdInfo::get())));
+			assert_eq!(
+				AssetRegistry::assets_by_reserve(statemine.into()),
+				vec![(LOCAL_ASSET_ID, STATEMINE_ASSET_MULTI_LOCATION.into())]
+			);
+			assert!(AssetRegistry::assets_by_reserve(MultiLocation::parent().into()).is_empty());
+		});
+	}
+}
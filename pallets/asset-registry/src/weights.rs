@@ -52,6 +52,21 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn register_reserve_asset() -> Weight;
 	fn unregister_reserve_asset() -> Weight;
+	fn update_reserve_asset_location() -> Weight;
+	fn remove_reserve_asset_alias() -> Weight;
+	fn set_asset_trust() -> Weight;
+	fn register_reserve_asset_with_deposit() -> Weight;
+	fn approve_reserve_asset_registration() -> Weight;
+	fn reject_reserve_asset_registration() -> Weight;
+	fn refund_reserve_asset_registration() -> Weight;
+	fn create_and_register_reserve_asset() -> Weight;
+	fn set_asset_units_per_second() -> Weight;
+	fn set_asset_status() -> Weight;
+	fn set_asset_flow_limits() -> Weight;
+	fn remove_deprecated_reserve_asset() -> Weight;
+	fn force_unregister_reserve_asset() -> Weight;
+	fn register_reserve_collection() -> Weight;
+	fn unregister_reserve_collection() -> Weight;
 }
 
 /// Weight functions for `pallet_asset_registry`.
@@ -59,10 +74,16 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Assets Asset (r:1 w:0)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:0)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
 	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry AssetMultiLocationId (r:0 w:1)
 	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingRegistrations (r:1 w:0)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
 	fn register_reserve_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123`
@@ -70,19 +91,333 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 21_998_000 picoseconds.
 		Weight::from_parts(22_970_000, 0)
 			.saturating_add(Weight::from_parts(0, 7762))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
 	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry AssetMultiLocationId (r:0 w:1)
 	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdTrust (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdTrust (max_values: None, max_size: Some(623), added: 3098, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdUnitsPerSecond (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdUnitsPerSecond (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdStatus (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlowLimits (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlowLimits (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlow (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
 	fn unregister_reserve_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `107`
 		//  Estimated: `4087`
 		// Minimum execution time: 17_862_000 picoseconds.
 		Weight::from_parts(18_454_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:2)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn update_reserve_asset_location() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 19_019_000 picoseconds.
+		Weight::from_parts(19_812_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn remove_reserve_asset_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4091`
+		// Minimum execution time: 11_619_000 picoseconds.
+		Weight::from_parts(12_104_000, 0)
+			.saturating_add(Weight::from_parts(0, 4091))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:0)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdTrust (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdTrust (max_values: None, max_size: Some(623), added: 3098, mode: MaxEncodedLen)
+	fn set_asset_trust() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_594_000 picoseconds.
+		Weight::from_parts(15_203_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:0)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingRegistrations (r:0 w:1)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry PendingMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry NextDerivativeAssetId (r:1 w:1)
+	/// Proof: AssetRegistry NextDerivativeAssetId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn register_reserve_asset_with_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 39_721_000 picoseconds.
+		Weight::from_parts(41_377_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: AssetRegistry PendingRegistrations (r:1 w:1)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry PendingMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn approve_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 31_694_000 picoseconds.
+		Weight::from_parts(33_015_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: AssetRegistry PendingRegistrations (r:1 w:1)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry PendingMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn reject_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 44_065_000 picoseconds.
+		Weight::from_parts(45_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: AssetRegistry PendingRegistrations (r:1 w:1)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry PendingMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn refund_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 39_600_000 picoseconds.
+		Weight::from_parts(41_250_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry NextDerivativeAssetId (r:1 w:1)
+	/// Proof: AssetRegistry NextDerivativeAssetId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	fn create_and_register_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 50_524_000 picoseconds.
+		Weight::from_parts(52_630_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:0)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdUnitsPerSecond (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdUnitsPerSecond (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_asset_units_per_second() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_381_000 picoseconds.
+		Weight::from_parts(14_981_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:0)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdStatus (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn set_asset_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_123_000 picoseconds.
+		Weight::from_parts(14_712_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:0)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlowLimits (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlowLimits (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlow (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn set_asset_flow_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_759_000 picoseconds.
+		Weight::from_parts(15_374_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AssetRegistry AssetIdStatus (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdTrust (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdTrust (max_values: None, max_size: Some(623), added: 3098, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdUnitsPerSecond (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdUnitsPerSecond (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlowLimits (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlowLimits (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlow (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	fn remove_deprecated_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 18_441_000 picoseconds.
+		Weight::from_parts(19_210_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdTrust (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdTrust (max_values: None, max_size: Some(623), added: 3098, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdUnitsPerSecond (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdUnitsPerSecond (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdStatus (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlowLimits (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlowLimits (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlow (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	fn force_unregister_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 16_934_000 picoseconds.
+		Weight::from_parts(17_640_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry CollectionIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry CollectionIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry CollectionMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry CollectionMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:0)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn register_reserve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 20_524_000 picoseconds.
+		Weight::from_parts(21_380_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AssetRegistry CollectionIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry CollectionIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry CollectionMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry CollectionMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn unregister_reserve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 15_283_000 picoseconds.
+		Weight::from_parts(15_920_000, 0)
 			.saturating_add(Weight::from_parts(0, 4087))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
@@ -92,10 +427,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 impl WeightInfo for () {
 	/// Storage: Assets Asset (r:1 w:0)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:0)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
 	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry AssetMultiLocationId (r:0 w:1)
 	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingRegistrations (r:1 w:0)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
 	fn register_reserve_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123`
@@ -103,21 +444,335 @@ impl WeightInfo for () {
 		// Minimum execution time: 21_998_000 picoseconds.
 		Weight::from_parts(22_970_000, 0)
 			.saturating_add(Weight::from_parts(0, 7762))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
 	}
 	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
 	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry AssetMultiLocationId (r:0 w:1)
 	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdTrust (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdTrust (max_values: None, max_size: Some(623), added: 3098, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdUnitsPerSecond (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdUnitsPerSecond (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdStatus (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlowLimits (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlowLimits (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlow (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
 	fn unregister_reserve_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `107`
 		//  Estimated: `4087`
 		// Minimum execution time: 17_862_000 picoseconds.
 		Weight::from_parts(18_454_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:2)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn update_reserve_asset_location() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 19_019_000 picoseconds.
+		Weight::from_parts(19_812_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn remove_reserve_asset_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4091`
+		// Minimum execution time: 11_619_000 picoseconds.
+		Weight::from_parts(12_104_000, 0)
+			.saturating_add(Weight::from_parts(0, 4091))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:0)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdTrust (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdTrust (max_values: None, max_size: Some(623), added: 3098, mode: MaxEncodedLen)
+	fn set_asset_trust() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_594_000 picoseconds.
+		Weight::from_parts(15_203_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:0)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingRegistrations (r:0 w:1)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry PendingMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry NextDerivativeAssetId (r:1 w:1)
+	/// Proof: AssetRegistry NextDerivativeAssetId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn register_reserve_asset_with_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 39_721_000 picoseconds.
+		Weight::from_parts(41_377_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: AssetRegistry PendingRegistrations (r:1 w:1)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry PendingMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn approve_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 31_694_000 picoseconds.
+		Weight::from_parts(33_015_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	/// Storage: AssetRegistry PendingRegistrations (r:1 w:1)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry PendingMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn reject_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 44_065_000 picoseconds.
+		Weight::from_parts(45_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: AssetRegistry PendingRegistrations (r:1 w:1)
+	/// Proof: AssetRegistry PendingRegistrations (max_values: None, max_size: Some(774), added: 3249, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry PendingMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry PendingMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn refund_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 39_600_000 picoseconds.
+		Weight::from_parts(41_250_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry NextDerivativeAssetId (r:1 w:1)
+	/// Proof: AssetRegistry NextDerivativeAssetId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	fn create_and_register_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 50_524_000 picoseconds.
+		Weight::from_parts(52_630_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:0)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdUnitsPerSecond (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdUnitsPerSecond (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_asset_units_per_second() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_381_000 picoseconds.
+		Weight::from_parts(14_981_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:0)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdStatus (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn set_asset_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_123_000 picoseconds.
+		Weight::from_parts(14_712_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:0)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlowLimits (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlowLimits (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlow (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn set_asset_flow_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_759_000 picoseconds.
+		Weight::from_parts(15_374_000, 0)
 			.saturating_add(Weight::from_parts(0, 4087))
 			.saturating_add(RocksDbWeight::get().reads(1))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
-}
\ No newline at end of file
+	/// Storage: AssetRegistry AssetIdStatus (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdTrust (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdTrust (max_values: None, max_size: Some(623), added: 3098, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdUnitsPerSecond (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdUnitsPerSecond (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlowLimits (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlowLimits (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlow (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	fn remove_deprecated_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 18_441_000 picoseconds.
+		Weight::from_parts(19_210_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	/// Storage: AssetRegistry AssetIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdMetadata (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdMetadata (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdTrust (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdTrust (max_values: None, max_size: Some(623), added: 3098, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdUnitsPerSecond (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdUnitsPerSecond (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdStatus (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlowLimits (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlowLimits (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdFlow (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdFlow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	fn force_unregister_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 16_934_000 picoseconds.
+		Weight::from_parts(17_640_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry CollectionIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry CollectionIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry CollectionMultiLocationId (r:1 w:1)
+	/// Proof: AssetRegistry CollectionMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetMultiLocationId (r:1 w:0)
+	/// Proof: AssetRegistry AssetMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn register_reserve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 20_524_000 picoseconds.
+		Weight::from_parts(21_380_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: AssetRegistry CollectionIdMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry CollectionIdMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry CollectionMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry CollectionMultiLocationId (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn unregister_reserve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 15_283_000 picoseconds.
+		Weight::from_parts(15_920_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+}
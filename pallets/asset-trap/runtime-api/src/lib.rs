@@ -0,0 +1,42 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the asset trap pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_core::H256;
+use sp_std::vec::Vec;
+use xcm::{VersionedMultiAssets, VersionedMultiLocation};
+
+sp_api::decl_runtime_apis! {
+	/// API to query the assets trapped and indexed by `pallet-asset-trap`.
+	pub trait AssetTrapApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// Assets trapped for `origin`, by hash, with the number of times they were trapped.
+		///
+		/// The assets are in the version they were trapped in, which is the one `claim_trapped`
+		/// and `ClaimAsset` expect.
+		fn traps(origin: VersionedMultiLocation) -> Vec<(H256, VersionedMultiAssets, u32)>;
+
+		/// Block from which the trap can be swept into the treasury.
+		fn trap_expiry(hash: H256) -> Option<BlockNumber>;
+	}
+}
@@ -0,0 +1,102 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarking setup for pallet-asset-trap
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as AssetTrap;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Convert, Saturating};
+use sp_std::boxed::Box;
+use xcm::latest::prelude::*;
+use xcm_executor::{traits::DropAssets, Assets};
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// Traps an amount of the native asset which the treasury and the sweeper can receive.
+	fn trapped_assets() -> (MultiLocation, Assets) {
+		let origin = MultiLocation::new(1, X1(Parachain(1000)));
+		let assets = Assets::from(MultiAsset::from((Here, 1_000_000_000_000u128)));
+		(origin, assets)
+	}
+
+	#[benchmark]
+	fn drop_assets() {
+		let (origin, assets) = trapped_assets();
+		let context = XcmContext::with_message_id([0; 32]);
+
+		#[block]
+		{
+			<AssetTrap<T> as DropAssets>::drop_assets(&origin, assets, &context);
+		}
+
+		assert_eq!(ExpiryQueueTail::<T>::get(), 1);
+	}
+
+	// Traps `a` assets `c` times, up to as many assets as holding can hold.
+	#[benchmark]
+	fn sweep_trap(c: Linear<1, 100>, a: Linear<1, 64>) {
+		let origin = MultiLocation::new(1, X1(Parachain(1000)));
+		let mut assets = Assets::new();
+		for index in 0..a {
+			assets.subsume(T::BenchmarkHelper::fungible_asset(index));
+		}
+		let context = XcmContext::with_message_id([0; 32]);
+		for _ in 0..c {
+			<AssetTrap<T> as DropAssets>::drop_assets(&origin, assets.clone(), &context);
+		}
+		let (hash, _) = ExpiryQueue::<T>::get(0).expect("assets were trapped; qed");
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::TrapExpiry::get()),
+		);
+		let caller = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), hash, c, a);
+
+		assert!(Traps::<T>::get(hash).is_none());
+	}
+
+	#[benchmark]
+	fn claim_trapped() -> Result<(), BenchmarkError> {
+		let (origin, assets) = trapped_assets();
+		let context = XcmContext::with_message_id([0; 32]);
+		<AssetTrap<T> as DropAssets>::drop_assets(&origin, assets.clone(), &context);
+		let (hash, _) = ExpiryQueue::<T>::get(0).expect("assets were trapped; qed");
+		let admin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let beneficiary = T::AccountIdToLocation::convert(whitelisted_caller());
+
+		#[extrinsic_call]
+		_(
+			admin as T::RuntimeOrigin,
+			Box::new(origin.into()),
+			Box::new(MultiAssets::from(assets).into()),
+			Box::new(beneficiary.into()),
+		);
+
+		assert!(Traps::<T>::get(hash).is_none());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(AssetTrap, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,518 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keeps an index of the assets trapped when XCM programs leave them in holding, and sweeps the
+//! traps nobody claimed into the treasury once they expire.
+//!
+//! The pallet is set in place of the asset trap it wraps, e.g. `pallet-xcm`, which keeps trapping
+//! and releasing the assets: claims with `ClaimAsset` work the same until a trap is swept. Set as
+//! the asset claimer too, it keeps the index up to date as traps are claimed.
+//!
+//! Expired traps can be swept by anyone with `sweep_trap`, for a share of the assets. Those left
+//! unswept for `IdleSweepDelay` more blocks are swept by `on_idle`, without a reward.
+//!
+//! Traps are listed by origin through the `AssetTrapApi` runtime API, and can be claimed with
+//! `claim_trapped` instead of building the `ClaimAsset` program.
+//!
+//! Only assets trapped once the pallet is in place are indexed. `pallet-xcm` stores its traps by
+//! hash alone, so the origin and assets of earlier traps can't be recovered to seed the index:
+//! they stay claimable with `ClaimAsset` as before, but are never listed or swept.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+pub(crate) const LOG_TARGET: &str = "runtime::asset-trap";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, storage::with_storage_layer};
+	use frame_system::pallet_prelude::*;
+	use sp_core::H256;
+	use sp_runtime::{
+		traits::{BlakeTwo256, Convert, Hash, Saturating},
+		Perbill,
+	};
+	use sp_std::{boxed::Box, vec::Vec};
+	use xcm::{latest::prelude::*, VersionedMultiAssets, VersionedMultiLocation};
+	use xcm_executor::{
+		traits::{ClaimAssets, DropAssets, TransactAsset},
+		Assets,
+	};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper {
+		/// A fungible asset which the treasury and sweepers can receive, distinct for each
+		/// `index`.
+		fn fungible_asset(index: u32) -> MultiAsset;
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The asset trap wrapped by the pallet, e.g. `pallet-xcm`.
+		type AssetTrap: DropAssets;
+
+		/// Releases the assets trapped by `AssetTrap`, e.g. `pallet-xcm`.
+		type AssetClaims: ClaimAssets;
+
+		/// Deposits the assets of swept and claimed traps.
+		type AssetTransactor: TransactAsset;
+
+		/// Origin claiming the traps of the location it converts into.
+		type ClaimOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+
+		/// Origin claiming traps of any location on behalf of their owners.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The location of the account receiving swept assets, e.g. the treasury.
+		type TreasuryLocation: Get<MultiLocation>;
+
+		/// Converts the account sweeping a trap into the location its reward is deposited to.
+		type AccountIdToLocation: Convert<Self::AccountId, MultiLocation>;
+
+		/// The number of blocks after which traps nobody claimed can be swept.
+		#[pallet::constant]
+		type TrapExpiry: Get<BlockNumberFor<Self>>;
+
+		/// The share of swept fungible assets paid to the account sweeping a trap.
+		#[pallet::constant]
+		type SweepReward: Get<Perbill>;
+
+		/// The number of blocks after a trap expires during which it is left to `sweep_trap`,
+		/// before `on_idle` sweeps it.
+		#[pallet::constant]
+		type IdleSweepDelay: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of traps swept by `on_idle` in a block.
+		#[pallet::constant]
+		type MaxSweepsPerBlock: Get<u32>;
+
+		type WeightInfo: WeightInfo;
+
+		/// Helper trait for benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	/// Assets trapped `count` times for the same origin.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct Trap<BlockNumber> {
+		pub origin: MultiLocation,
+		pub assets: VersionedMultiAssets,
+		/// The block the assets were last trapped in.
+		pub trapped_at: BlockNumber,
+		pub count: u32,
+	}
+
+	pub type TrapOf<T> = Trap<BlockNumberFor<T>>;
+
+	/// Trapped assets, by the hash the asset trap identifies them with.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type Traps<T: Config> = StorageMap<_, Identity, H256, TrapOf<T>>;
+
+	/// Hashes of the assets trapped for each origin.
+	#[pallet::storage]
+	pub type TrapsByOrigin<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, MultiLocation, Identity, H256, ()>;
+
+	/// Traps in the order they were trapped in, which is also the order they expire in. A trap
+	/// trapped again is queued again, with the block it was trapped in.
+	#[pallet::storage]
+	pub type ExpiryQueue<T: Config> = StorageMap<_, Twox64Concat, u64, (H256, BlockNumberFor<T>)>;
+
+	/// Position of the next trap of `ExpiryQueue` to expire.
+	#[pallet::storage]
+	pub type ExpiryQueueHead<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Position of the next trap queued in `ExpiryQueue`.
+	#[pallet::storage]
+	pub type ExpiryQueueTail<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Assets trapped `count` times were swept into the treasury, by `sweeper` or `on_idle`.
+		TrapSwept {
+			hash: H256,
+			origin: MultiLocation,
+			assets: VersionedMultiAssets,
+			count: u32,
+			sweeper: Option<T::AccountId>,
+		},
+		/// Assets trapped for `origin` were claimed into `beneficiary` with `claim_trapped`.
+		TrapClaimed {
+			hash: H256,
+			origin: MultiLocation,
+			assets: VersionedMultiAssets,
+			beneficiary: MultiLocation,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No assets are trapped under the hash
+		TrapNotFound,
+		/// The trap has not expired yet
+		TrapNotExpired,
+		/// The location or assets cannot be converted into the current XCM version
+		BadVersion,
+		/// The claimed or swept assets could not be deposited into the beneficiary or treasury
+		DepositFailed,
+		/// The trap was trapped more times, or holds more assets, than the call was weighed for
+		WitnessTooLow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expired(remaining_weight)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Sweep an expired trap into the treasury, for a `SweepReward` share of its fungible
+		/// assets.
+		///
+		/// The call is weighed with `count` and `assets_count`, which may not be lower than the
+		/// number of times the assets were trapped and the number of assets, as listed by the
+		/// `AssetTrapApi` runtime API.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::sweep_trap(*count, *assets_count))]
+		pub fn sweep_trap(
+			origin: OriginFor<T>,
+			hash: H256,
+			count: u32,
+			assets_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let sweeper = ensure_signed(origin)?;
+
+			let trap = Traps::<T>::get(hash).ok_or(Error::<T>::TrapNotFound)?;
+			ensure!(Self::is_expired(&trap), Error::<T>::TrapNotExpired);
+			ensure!(
+				trap.count <= count && Self::assets_len(&trap.assets) <= assets_count,
+				Error::<T>::WitnessTooLow
+			);
+
+			let weight = Self::sweep_weight(&trap);
+			Self::do_sweep(hash, trap, Some(sweeper))?;
+			Ok(Some(weight).into())
+		}
+
+		/// Claim the `assets` trapped for `origin_location` into `beneficiary`.
+		///
+		/// The claim is made by `AdminOrigin`, or by the owner of the trap through `ClaimOrigin`.
+		/// `assets` must be the exact set of assets trapped, in the version they were trapped in,
+		/// as listed by the `AssetTrapApi` runtime API.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			T::WeightInfo::claim_trapped().saturating_mul(Pallet::<T>::assets_len(assets).into())
+		)]
+		pub fn claim_trapped(
+			origin: OriginFor<T>,
+			origin_location: Box<VersionedMultiLocation>,
+			assets: Box<VersionedMultiAssets>,
+			beneficiary: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			let origin_location: MultiLocation =
+				(*origin_location).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			if let Err(origin) = T::AdminOrigin::try_origin(origin) {
+				let claimer = T::ClaimOrigin::ensure_origin(origin)?;
+				ensure!(claimer == origin_location, DispatchError::BadOrigin);
+			}
+			let beneficiary: MultiLocation =
+				(*beneficiary).try_into().map_err(|()| Error::<T>::BadVersion)?;
+
+			let versioned = *assets;
+			let assets: MultiAssets =
+				versioned.clone().try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let hash = BlakeTwo256::hash_of(&(origin_location, &versioned));
+			let context = XcmContext::with_message_id(hash.0);
+
+			ensure!(
+				<Self as ClaimAssets>::claim_assets(
+					&origin_location,
+					&Self::ticket(&versioned),
+					&assets,
+					&context,
+				),
+				Error::<T>::TrapNotFound
+			);
+			for asset in assets.inner() {
+				T::AssetTransactor::deposit_asset(asset, &beneficiary, Some(&context))
+					.map_err(|_| Error::<T>::DepositFailed)?;
+			}
+
+			Self::deposit_event(Event::TrapClaimed {
+				hash,
+				origin: origin_location,
+				assets: versioned,
+				beneficiary,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Assets trapped for `origin`, by hash, with the number of times they were trapped.
+		pub fn traps(origin: VersionedMultiLocation) -> Vec<(H256, VersionedMultiAssets, u32)> {
+			let Ok(origin) = MultiLocation::try_from(origin) else { return Vec::new() };
+
+			TrapsByOrigin::<T>::iter_key_prefix(origin)
+				.filter_map(|hash| {
+					Traps::<T>::get(hash).map(|trap| (hash, trap.assets, trap.count))
+				})
+				.collect()
+		}
+
+		/// Block from which the trap can be swept.
+		pub fn trap_expiry(hash: H256) -> Option<BlockNumberFor<T>> {
+			Traps::<T>::get(hash).map(|trap| trap.trapped_at.saturating_add(T::TrapExpiry::get()))
+		}
+
+		// Claims are weighed per asset, as each asset is deposited separately.
+		fn assets_len(assets: &VersionedMultiAssets) -> u32 {
+			let len = match assets {
+				VersionedMultiAssets::V2(assets) => assets.len(),
+				VersionedMultiAssets::V3(assets) => assets.len(),
+			};
+			(len as u32).max(1)
+		}
+
+		// The ticket releasing assets trapped in the version of `assets`.
+		fn ticket(assets: &VersionedMultiAssets) -> MultiLocation {
+			let version = match assets {
+				VersionedMultiAssets::V2(_) => 2,
+				VersionedMultiAssets::V3(_) => 3,
+			};
+			GeneralIndex(version).into()
+		}
+
+		// Removes one of the claims on the trap, and the trap with the last of them.
+		fn release(hash: H256) {
+			Traps::<T>::mutate_exists(hash, |maybe_trap| {
+				if let Some(trap) = maybe_trap {
+					trap.count.saturating_dec();
+					if trap.count == 0 {
+						TrapsByOrigin::<T>::remove(trap.origin, hash);
+						*maybe_trap = None;
+					}
+				}
+			});
+		}
+
+		fn sweep_weight(trap: &TrapOf<T>) -> Weight {
+			T::WeightInfo::sweep_trap(trap.count, Self::assets_len(&trap.assets))
+		}
+
+		fn is_expired(trap: &TrapOf<T>) -> bool {
+			frame_system::Pallet::<T>::block_number()
+				>= trap.trapped_at.saturating_add(T::TrapExpiry::get())
+		}
+
+		// Sweeps traps expired for `IdleSweepDelay` from the head of `ExpiryQueue`, within `limit`.
+		fn sweep_expired(limit: Weight) -> Weight {
+			// reading the head of the queue, its entry and trap, and popping the entry
+			let step = T::DbWeight::get().reads_writes(3, 2);
+			let max_sweep = T::BlockWeights::get().max_block;
+			let mut used = Weight::zero();
+
+			for _ in 0..T::MaxSweepsPerBlock::get() {
+				if used.saturating_add(step).any_gt(limit) {
+					break;
+				}
+				used.saturating_accrue(step);
+
+				let head = ExpiryQueueHead::<T>::get();
+				let (hash, trapped_at) = match ExpiryQueue::<T>::get(head) {
+					Some(entry) => entry,
+					None => break,
+				};
+				let swept_at = trapped_at
+					.saturating_add(T::TrapExpiry::get())
+					.saturating_add(T::IdleSweepDelay::get());
+				if frame_system::Pallet::<T>::block_number() < swept_at {
+					break;
+				}
+
+				// traps swept already, or trapped again since, are skipped
+				let trap = Traps::<T>::get(hash).filter(|trap| trap.trapped_at == trapped_at);
+				let weight = trap.as_ref().map(Self::sweep_weight).unwrap_or_default();
+				// traps too heavy to be swept in a block are left to be claimed
+				if weight.all_lte(max_sweep) && used.saturating_add(weight).any_gt(limit) {
+					break;
+				}
+				ExpiryQueue::<T>::remove(head);
+				ExpiryQueueHead::<T>::put(head.saturating_add(1));
+
+				if let Some(trap) = trap.filter(|_| weight.all_lte(max_sweep)) {
+					used.saturating_accrue(weight);
+					// a failed sweep is reverted, the trap can still be claimed or swept
+					if let Err(error) = with_storage_layer(|| Self::do_sweep(hash, trap, None)) {
+						log::warn!(target: LOG_TARGET, "cannot sweep trap {hash:?}: {error:?}");
+					}
+				}
+			}
+
+			used
+		}
+
+		// Releases the assets of the trap from `AssetTrap` and deposits them into the treasury.
+		// Claims made in the meantime release fewer of them, down to none.
+		fn do_sweep(hash: H256, trap: TrapOf<T>, sweeper: Option<T::AccountId>) -> DispatchResult {
+			let assets: MultiAssets =
+				trap.assets.clone().try_into().map_err(|()| Error::<T>::BadVersion)?;
+			Traps::<T>::remove(hash);
+			TrapsByOrigin::<T>::remove(trap.origin, hash);
+
+			let ticket = Self::ticket(&trap.assets);
+			let context = XcmContext::with_message_id(hash.0);
+
+			let mut count = 0;
+			while count < trap.count
+				&& T::AssetClaims::claim_assets(&trap.origin, &ticket, &assets, &context)
+			{
+				count += 1;
+			}
+
+			// the fungible assets released by each claim are deposited at once
+			let sweeper_location = sweeper.clone().map(T::AccountIdToLocation::convert);
+			for asset in assets.inner().iter().filter(|_| count > 0) {
+				let released: MultiAsset = match asset.fun {
+					Fungible(amount) => (asset.id, amount.saturating_mul(count.into())).into(),
+					NonFungible(_) => asset.clone(),
+				};
+				Self::deposit(&released, sweeper_location.as_ref(), &context)?;
+			}
+
+			Self::deposit_event(Event::TrapSwept {
+				hash,
+				origin: trap.origin,
+				assets: trap.assets,
+				count,
+				sweeper,
+			});
+			Ok(())
+		}
+
+		// Deposits `asset` into the treasury, less the reward of the sweeper if there is one.
+		fn deposit(
+			asset: &MultiAsset,
+			sweeper: Option<&MultiLocation>,
+			context: &XcmContext,
+		) -> DispatchResult {
+			let asset = match (sweeper, &asset.fun) {
+				(Some(sweeper), Fungible(amount)) => {
+					let reward = T::SweepReward::get() * *amount;
+					// the sweeper may not be able to receive the asset, the treasury keeps it all
+					if reward > 0
+						&& T::AssetTransactor::deposit_asset(
+							&(asset.id, reward).into(),
+							sweeper,
+							Some(context),
+						)
+						.is_ok()
+					{
+						(asset.id, amount.saturating_sub(reward)).into()
+					} else {
+						asset.clone()
+					}
+				},
+				_ => asset.clone(),
+			};
+
+			T::AssetTransactor::deposit_asset(&asset, &T::TreasuryLocation::get(), Some(context))
+				.map_err(|_| Error::<T>::DepositFailed.into())
+		}
+	}
+
+	impl<T: Config> DropAssets for Pallet<T> {
+		fn drop_assets(origin: &MultiLocation, assets: Assets, context: &XcmContext) -> Weight {
+			if assets.is_empty() {
+				return T::AssetTrap::drop_assets(origin, assets, context);
+			}
+
+			// identify the trap the way `pallet-xcm` does
+			let versioned = VersionedMultiAssets::from(MultiAssets::from(assets.clone()));
+			let hash = BlakeTwo256::hash_of(&(origin, &versioned));
+			let now = frame_system::Pallet::<T>::block_number();
+
+			Traps::<T>::mutate(hash, |maybe_trap| match maybe_trap {
+				Some(trap) => {
+					trap.trapped_at = now;
+					trap.count.saturating_inc();
+				},
+				None => {
+					*maybe_trap = Some(Trap {
+						origin: *origin,
+						assets: versioned,
+						trapped_at: now,
+						count: 1,
+					});
+					TrapsByOrigin::<T>::insert(origin, hash, ());
+				},
+			});
+			let tail = ExpiryQueueTail::<T>::get();
+			ExpiryQueue::<T>::insert(tail, (hash, now));
+			ExpiryQueueTail::<T>::put(tail.saturating_add(1));
+
+			T::WeightInfo::drop_assets()
+				.saturating_add(T::AssetTrap::drop_assets(origin, assets, context))
+		}
+	}
+
+	impl<T: Config> ClaimAssets for Pallet<T> {
+		fn claim_assets(
+			origin: &MultiLocation,
+			ticket: &MultiLocation,
+			assets: &MultiAssets,
+			context: &XcmContext,
+		) -> bool {
+			if !T::AssetClaims::claim_assets(origin, ticket, assets, context) {
+				return false;
+			}
+
+			// identify the trap released the way `pallet-xcm` does
+			let versioned = VersionedMultiAssets::from(assets.clone());
+			let versioned = match (ticket.parents, &ticket.interior) {
+				(0, X1(GeneralIndex(version))) => versioned.into_version(*version as u32),
+				_ => Ok(versioned),
+			};
+			if let Ok(versioned) = versioned {
+				Self::release(BlakeTwo256::hash_of(&(origin, &versioned)));
+			}
+			true
+		}
+	}
+}
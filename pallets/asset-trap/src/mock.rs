@@ -0,0 +1,210 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_asset_trap;
+use frame_support::{
+	parameter_types,
+	storage::unhashed,
+	traits::{ConstU32, ConstU64},
+	weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use parity_scale_codec::Encode;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Convert, Hash, IdentityLookup, MapSuccess, Morph},
+	BuildStorage, Perbill,
+};
+use sp_std::cell::RefCell;
+use xcm::{latest::prelude::*, VersionedMultiAssets};
+use xcm_executor::{
+	traits::{ClaimAssets, DropAssets, TransactAsset},
+	Assets,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		AssetTrap: pallet_asset_trap,
+	}
+);
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+/// Blocks after which traps expire.
+pub const TRAP_EXPIRY: u64 = 10;
+/// Blocks after expiry before `on_idle` sweeps traps.
+pub const IDLE_SWEEP_DELAY: u64 = 5;
+/// Account which can't receive assets.
+pub const UNFUNDABLE_ACCOUNT: u64 = 666;
+/// Asset which can't be deposited into any account.
+pub const UNTRANSACTABLE_ASSET: MultiLocation =
+	MultiLocation { parents: 0, interior: X1(GeneralIndex(666)) };
+
+parameter_types! {
+	pub TreasuryLocation: MultiLocation = AccountIndex64 { network: None, index: 0 }.into();
+	pub const SweepReward: Perbill = Perbill::from_percent(10);
+}
+
+thread_local! {
+	/// Assets deposited by `MockTransactor`, with their beneficiary.
+	pub static DEPOSITED: RefCell<Vec<(MultiAsset, MultiLocation)>> = RefCell::new(Vec::new());
+}
+
+/// Traps and claims assets the way `pallet-xcm` does, counting traps by hash in storage.
+pub struct MockXcm;
+impl MockXcm {
+	pub fn hash(origin: &MultiLocation, assets: MultiAssets) -> H256 {
+		BlakeTwo256::hash_of(&(origin, VersionedMultiAssets::from(assets)))
+	}
+
+	pub fn trapped(hash: H256) -> u32 {
+		unhashed::get_or_default(&Self::key(hash))
+	}
+
+	fn key(hash: H256) -> Vec<u8> {
+		(b"MockXcm::AssetTraps", hash).encode()
+	}
+}
+
+impl DropAssets for MockXcm {
+	fn drop_assets(origin: &MultiLocation, assets: Assets, _context: &XcmContext) -> Weight {
+		if !assets.is_empty() {
+			let hash = Self::hash(origin, assets.into());
+			unhashed::put(&Self::key(hash), &(Self::trapped(hash) + 1));
+		}
+		Weight::zero()
+	}
+}
+
+impl ClaimAssets for MockXcm {
+	fn claim_assets(
+		origin: &MultiLocation,
+		_ticket: &MultiLocation,
+		assets: &MultiAssets,
+		_context: &XcmContext,
+	) -> bool {
+		let hash = Self::hash(origin, assets.clone());
+		match Self::trapped(hash) {
+			0 => false,
+			count => {
+				unhashed::put(&Self::key(hash), &(count - 1));
+				true
+			},
+		}
+	}
+}
+
+/// Records deposits, failing those to `UNFUNDABLE_ACCOUNT` and those of `UNTRANSACTABLE_ASSET`.
+pub struct MockTransactor;
+impl TransactAsset for MockTransactor {
+	fn deposit_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		_context: Option<&XcmContext>,
+	) -> XcmResult {
+		if *who == AccountToLocation::convert(UNFUNDABLE_ACCOUNT) {
+			return Err(XcmError::FailedToTransactAsset("unfundable"));
+		}
+		if what.id == Concrete(UNTRANSACTABLE_ASSET) {
+			return Err(XcmError::FailedToTransactAsset("untransactable"));
+		}
+		DEPOSITED.with(|deposited| deposited.borrow_mut().push((what.clone(), *who)));
+		Ok(())
+	}
+}
+
+pub struct AccountToLocation;
+impl Convert<u64, MultiLocation> for AccountToLocation {
+	fn convert(index: u64) -> MultiLocation {
+		AccountIndex64 { network: None, index }.into()
+	}
+}
+
+impl Morph<u64> for AccountToLocation {
+	type Outcome = MultiLocation;
+
+	fn morph(index: u64) -> MultiLocation {
+		Self::convert(index)
+	}
+}
+
+impl pallet_asset_trap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetTrap = MockXcm;
+	type AssetClaims = MockXcm;
+	type AssetTransactor = MockTransactor;
+	type ClaimOrigin = MapSuccess<EnsureSigned<u64>, AccountToLocation>;
+	type AdminOrigin = EnsureRoot<u64>;
+	type TreasuryLocation = TreasuryLocation;
+	type AccountIdToLocation = AccountToLocation;
+	type TrapExpiry = ConstU64<TRAP_EXPIRY>;
+	type SweepReward = SweepReward;
+	type IdleSweepDelay = ConstU64<IDLE_SWEEP_DELAY>;
+	type MaxSweepsPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_asset_trap::BenchmarkHelper for MockBenchmarkHelper {
+	fn fungible_asset(index: u32) -> MultiAsset {
+		(MultiLocation::new(0, X1(GeneralIndex(index.into()))), 1_000_000_000_000u128).into()
+	}
+}
+
+pub fn deposited() -> Vec<(MultiAsset, MultiLocation)> {
+	DEPOSITED.with(|deposited| deposited.borrow().clone())
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	DEPOSITED.with(|deposited| deposited.borrow_mut().clear());
+
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,422 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks},
+	weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{traits::Convert, DispatchError};
+use xcm::{latest::prelude::*, VersionedMultiLocation};
+use xcm_executor::{
+	traits::{ClaimAssets, DropAssets},
+	Assets,
+};
+
+use crate::{mock::*, Error, Event, ExpiryQueueHead, Traps, TrapsByOrigin, WeightInfo};
+
+const SWEEPER: u64 = 1;
+
+fn origin() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(1000)))
+}
+
+fn assets(amount: u128) -> MultiAssets {
+	(Here, amount).into()
+}
+
+fn trap(amount: u128) -> H256 {
+	let assets = assets(amount);
+	let context = XcmContext::with_message_id([0; 32]);
+	AssetTrap::drop_assets(&origin(), Assets::from(assets.clone().into_inner()), &context);
+	MockXcm::hash(&origin(), assets)
+}
+
+fn expire() {
+	System::set_block_number(System::block_number() + TRAP_EXPIRY);
+}
+
+fn expire_for_on_idle() {
+	System::set_block_number(System::block_number() + TRAP_EXPIRY + IDLE_SWEEP_DELAY);
+}
+
+fn claim(amount: u128) -> bool {
+	let context = XcmContext::with_message_id([0; 32]);
+	MockXcm::claim_assets(&origin(), &Here.into(), &assets(amount), &context)
+}
+
+#[test]
+fn dropped_assets_are_trapped_and_indexed() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		assert_eq!(MockXcm::trapped(hash), 1);
+
+		let indexed = Traps::<Test>::get(hash).unwrap();
+		assert_eq!(indexed.origin, origin());
+		assert_eq!(indexed.assets, assets(100).into());
+		assert_eq!((indexed.trapped_at, indexed.count), (1, 1));
+
+		System::set_block_number(5);
+		assert_eq!(trap(100), hash);
+		assert_eq!(MockXcm::trapped(hash), 2);
+		let indexed = Traps::<Test>::get(hash).unwrap();
+		assert_eq!((indexed.trapped_at, indexed.count), (5, 2));
+	});
+}
+
+#[test]
+fn sweep_trap_fails_before_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetTrap::sweep_trap(RuntimeOrigin::signed(SWEEPER), H256::zero(), 1, 1),
+			Error::<Test>::TrapNotFound
+		);
+
+		let hash = trap(100);
+		System::set_block_number(TRAP_EXPIRY);
+		assert_noop!(
+			AssetTrap::sweep_trap(RuntimeOrigin::signed(SWEEPER), hash, 1, 1),
+			Error::<Test>::TrapNotExpired
+		);
+
+		// claims keep working until the trap is swept
+		assert!(claim(100));
+	});
+}
+
+#[test]
+fn sweep_trap_rewards_the_sweeper() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		expire();
+
+		assert_ok!(AssetTrap::sweep_trap(RuntimeOrigin::signed(SWEEPER), hash, 1, 1));
+
+		assert_eq!(MockXcm::trapped(hash), 0);
+		assert!(Traps::<Test>::get(hash).is_none());
+		assert_eq!(
+			deposited(),
+			vec![
+				((Here, 10).into(), AccountToLocation::convert(SWEEPER)),
+				((Here, 90).into(), TreasuryLocation::get()),
+			]
+		);
+		System::assert_last_event(
+			Event::<Test>::TrapSwept {
+				hash,
+				origin: origin(),
+				assets: assets(100).into(),
+				count: 1,
+				sweeper: Some(SWEEPER),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn unpaid_rewards_go_to_the_treasury() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		expire();
+
+		assert_ok!(AssetTrap::sweep_trap(RuntimeOrigin::signed(UNFUNDABLE_ACCOUNT), hash, 1, 1));
+
+		assert_eq!(deposited(), vec![((Here, 100).into(), TreasuryLocation::get())]);
+	});
+}
+
+#[test]
+fn sweep_trap_is_weighed_for_the_whole_trap() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		trap(100);
+		expire();
+		let sweep_trap = |count, assets_count| {
+			AssetTrap::sweep_trap(RuntimeOrigin::signed(SWEEPER), hash, count, assets_count)
+		};
+
+		assert_noop!(sweep_trap(1, 1), Error::<Test>::WitnessTooLow);
+		assert_noop!(sweep_trap(2, 0), Error::<Test>::WitnessTooLow);
+		let info = sweep_trap(3, 2).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::sweep_trap(2, 1)));
+
+		// the assets released by both claims are deposited at once
+		assert_eq!(MockXcm::trapped(hash), 0);
+		assert_eq!(
+			deposited(),
+			vec![
+				((Here, 20).into(), AccountToLocation::convert(SWEEPER)),
+				((Here, 180).into(), TreasuryLocation::get()),
+			]
+		);
+	});
+}
+
+#[test]
+fn failed_sweeps_are_reverted() {
+	new_test_ext().execute_with(|| {
+		let untransactable: MultiAssets = (UNTRANSACTABLE_ASSET, 100u128).into();
+		let context = XcmContext::with_message_id([0; 32]);
+		AssetTrap::drop_assets(&origin(), untransactable.clone().into_inner().into(), &context);
+		let hash = MockXcm::hash(&origin(), untransactable);
+		expire();
+
+		assert_noop!(
+			AssetTrap::sweep_trap(RuntimeOrigin::signed(SWEEPER), hash, 1, 1),
+			Error::<Test>::DepositFailed
+		);
+
+		// on_idle moves on to the next traps, leaving the trap to be claimed
+		System::set_block_number(System::block_number() + IDLE_SWEEP_DELAY);
+		AssetTrap::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(ExpiryQueueHead::<Test>::get(), 1);
+		assert_eq!(MockXcm::trapped(hash), 1);
+		assert!(Traps::<Test>::get(hash).is_some());
+		assert!(TrapsByOrigin::<Test>::contains_key(origin(), hash));
+		assert!(deposited().is_empty());
+	});
+}
+
+#[test]
+fn claimed_assets_are_not_swept() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		trap(100);
+		assert!(claim(100));
+		expire();
+
+		assert_ok!(AssetTrap::sweep_trap(RuntimeOrigin::signed(SWEEPER), hash, 1, 1));
+		assert_eq!(deposited().len(), 2);
+
+		// the trap is fully claimed, only the index is left
+		let hash = trap(50);
+		assert!(claim(50));
+		expire();
+
+		assert_ok!(AssetTrap::sweep_trap(RuntimeOrigin::signed(SWEEPER), hash, 1, 1));
+		assert_eq!(deposited().len(), 2);
+		System::assert_last_event(
+			Event::<Test>::TrapSwept {
+				hash,
+				origin: origin(),
+				assets: assets(50).into(),
+				count: 0,
+				sweeper: Some(SWEEPER),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn on_idle_sweeps_expired_traps_in_order() {
+	new_test_ext().execute_with(|| {
+		let first = trap(100);
+		let second = trap(200);
+		System::set_block_number(2);
+		let third = trap(300);
+		// trapped again, so expiring later
+		trap(100);
+
+		System::set_block_number(1 + TRAP_EXPIRY + IDLE_SWEEP_DELAY);
+		AssetTrap::on_idle(System::block_number(), Weight::MAX);
+
+		// the first entry of `first` is skipped, at most two entries are read per block
+		assert!(Traps::<Test>::get(first).is_some());
+		assert!(Traps::<Test>::get(second).is_none());
+		assert!(Traps::<Test>::get(third).is_some());
+		assert_eq!(deposited(), vec![((Here, 200).into(), TreasuryLocation::get())]);
+		assert_eq!(ExpiryQueueHead::<Test>::get(), 2);
+
+		// nothing else expired yet
+		AssetTrap::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(ExpiryQueueHead::<Test>::get(), 2);
+
+		System::set_block_number(2 + TRAP_EXPIRY + IDLE_SWEEP_DELAY);
+		AssetTrap::on_idle(System::block_number(), Weight::MAX);
+		assert!(Traps::<Test>::get(first).is_none());
+		assert!(Traps::<Test>::get(third).is_none());
+		assert_eq!(MockXcm::trapped(first), 0);
+	});
+}
+
+#[test]
+fn on_idle_leaves_expired_traps_to_sweepers_for_a_while() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		expire();
+
+		AssetTrap::on_idle(System::block_number(), Weight::MAX);
+		assert!(Traps::<Test>::get(hash).is_some());
+		assert_eq!(ExpiryQueueHead::<Test>::get(), 0);
+
+		System::set_block_number(System::block_number() + IDLE_SWEEP_DELAY - 1);
+		AssetTrap::on_idle(System::block_number(), Weight::MAX);
+		assert!(Traps::<Test>::get(hash).is_some());
+
+		// the trap is still swept for a reward
+		assert_ok!(AssetTrap::sweep_trap(RuntimeOrigin::signed(SWEEPER), hash, 1, 1));
+		assert_eq!(deposited().len(), 2);
+
+		// the queue entry of a swept trap is dropped
+		System::set_block_number(System::block_number() + 1);
+		AssetTrap::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(ExpiryQueueHead::<Test>::get(), 1);
+		assert_eq!(deposited().len(), 2);
+	});
+}
+
+#[test]
+fn on_idle_sweeps_without_a_reward() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		expire_for_on_idle();
+
+		AssetTrap::on_idle(System::block_number(), Weight::MAX);
+
+		assert!(Traps::<Test>::get(hash).is_none());
+		assert_eq!(deposited(), vec![((Here, 100).into(), TreasuryLocation::get())]);
+		System::assert_last_event(
+			Event::<Test>::TrapSwept {
+				hash,
+				origin: origin(),
+				assets: assets(100).into(),
+				count: 1,
+				sweeper: None,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn on_idle_respects_the_weight_limit() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		expire_for_on_idle();
+
+		assert_eq!(AssetTrap::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+		assert!(Traps::<Test>::get(hash).is_some());
+	});
+}
+
+#[test]
+fn traps_are_listed_by_origin() {
+	new_test_ext().execute_with(|| {
+		let first = trap(100);
+		let second = trap(200);
+		trap(200);
+		let other = MultiLocation::new(1, X1(Parachain(2000)));
+		let context = XcmContext::with_message_id([0; 32]);
+		AssetTrap::drop_assets(&other, assets(300).into_inner().into(), &context);
+
+		let mut traps = AssetTrap::traps(origin().into());
+		traps.sort_by_key(|(_, _, count)| *count);
+		assert_eq!(traps, vec![(first, assets(100).into(), 1), (second, assets(200).into(), 2)]);
+		assert_eq!(AssetTrap::traps(other.into()).len(), 1);
+		assert_eq!(AssetTrap::trap_expiry(first), Some(1 + TRAP_EXPIRY));
+		assert_eq!(AssetTrap::trap_expiry(H256::zero()), None);
+	});
+}
+
+#[test]
+fn claims_are_removed_from_the_index() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		trap(100);
+		let context = XcmContext::with_message_id([0; 32]);
+		let ticket = GeneralIndex(3).into();
+
+		assert!(AssetTrap::claim_assets(&origin(), &ticket, &assets(100), &context));
+		assert_eq!(Traps::<Test>::get(hash).unwrap().count, 1);
+
+		assert!(AssetTrap::claim_assets(&origin(), &Here.into(), &assets(100), &context));
+		assert!(Traps::<Test>::get(hash).is_none());
+		assert!(!TrapsByOrigin::<Test>::contains_key(origin(), hash));
+		assert!(AssetTrap::traps(origin().into()).is_empty());
+
+		assert!(!AssetTrap::claim_assets(&origin(), &Here.into(), &assets(100), &context));
+	});
+}
+
+#[test]
+fn claim_trapped_deposits_into_the_beneficiary() {
+	new_test_ext().execute_with(|| {
+		let hash = trap(100);
+		let beneficiary = AccountToLocation::convert(2);
+
+		assert_ok!(AssetTrap::claim_trapped(
+			RuntimeOrigin::root(),
+			Box::new(origin().into()),
+			Box::new(assets(100).into()),
+			Box::new(beneficiary.into()),
+		));
+
+		assert_eq!(MockXcm::trapped(hash), 0);
+		assert!(Traps::<Test>::get(hash).is_none());
+		assert_eq!(deposited(), vec![((Here, 100).into(), beneficiary)]);
+		System::assert_last_event(
+			Event::<Test>::TrapClaimed {
+				hash,
+				origin: origin(),
+				assets: assets(100).into(),
+				beneficiary,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn claim_trapped_is_restricted_to_the_trap_origin() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountToLocation::convert(7);
+		let context = XcmContext::with_message_id([0; 32]);
+		AssetTrap::drop_assets(&owner, assets(100).into_inner().into(), &context);
+		let claim_trapped = |who: u64| {
+			AssetTrap::claim_trapped(
+				RuntimeOrigin::signed(who),
+				Box::new(owner.into()),
+				Box::new(assets(100).into()),
+				Box::new(VersionedMultiLocation::from(owner)),
+			)
+		};
+
+		assert_noop!(claim_trapped(8), DispatchError::BadOrigin);
+		assert_ok!(claim_trapped(7));
+		assert_eq!(deposited(), vec![((Here, 100).into(), owner)]);
+	});
+}
+
+#[test]
+fn claim_trapped_fails_without_a_trap() {
+	new_test_ext().execute_with(|| {
+		trap(100);
+
+		assert_noop!(
+			AssetTrap::claim_trapped(
+				RuntimeOrigin::root(),
+				Box::new(origin().into()),
+				Box::new(assets(50).into()),
+				Box::new(origin().into()),
+			),
+			Error::<Test>::TrapNotFound
+		);
+	});
+}
@@ -0,0 +1,189 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_asset_trap
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-03-04, STEPS: `20`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bm-trappist`, CPU: `<UNKNOWN>`
+//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/trappist-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=20
+// --repeat=10
+// --pallet=pallet_asset_trap
+// --extrinsic=*
+// --wasm-execution=compiled
+// --template=./templates/frame-weight-template.hbs
+// --output=./pallets/asset-trap/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_asset_trap.
+pub trait WeightInfo {
+	fn drop_assets() -> Weight;
+	fn sweep_trap(c: u32, a: u32, ) -> Weight;
+	fn claim_trapped() -> Weight;
+}
+
+/// Weights for pallet_asset_trap using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::ExpiryQueueTail` (r:1 w:1)
+	/// Proof: `AssetTrap::ExpiryQueueTail` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `AssetTrap::ExpiryQueue` (r:0 w:1)
+	/// Proof: `AssetTrap::ExpiryQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn drop_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3507)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:64 w:64)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:128 w:128)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	/// The range of component `a` is `[1, 64]`.
+	fn sweep_trap(c: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312 + a * (291 ±0)`
+		//  Estimated: `3777 + a * (7903 ±0)`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3777)
+			// Standard Error: 2_113
+			.saturating_add(Weight::from_parts(1_106_000, 0).saturating_mul(c.into()))
+			// Standard Error: 3_301
+			.saturating_add(Weight::from_parts(43_517_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 7903).saturating_mul(a.into()))
+	}
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_trapped() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298`
+		//  Estimated: `3763`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(48_000_000, 3763)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::ExpiryQueueTail` (r:1 w:1)
+	/// Proof: `AssetTrap::ExpiryQueueTail` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `AssetTrap::ExpiryQueue` (r:0 w:1)
+	/// Proof: `AssetTrap::ExpiryQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn drop_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3507)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:64 w:64)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:128 w:128)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	/// The range of component `a` is `[1, 64]`.
+	fn sweep_trap(c: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312 + a * (291 ±0)`
+		//  Estimated: `3777 + a * (7903 ±0)`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3777)
+			// Standard Error: 2_113
+			.saturating_add(Weight::from_parts(1_106_000, 0).saturating_mul(c.into()))
+			// Standard Error: 3_301
+			.saturating_add(Weight::from_parts(43_517_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 7903).saturating_mul(a.into()))
+	}
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_trapped() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298`
+		//  Estimated: `3763`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(48_000_000, 3763)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
@@ -57,6 +57,26 @@ benchmarks! {
 		);
 	}
 
+	drop_assets_non_fungible {
+		let origin = MultiLocation::default();
+		let location: MultiLocation = Parachain(1).into();
+		T::register_collection(location);
+		let asset = MultiAsset {
+			id: XcmAssetId::Concrete(location),
+			fun: Fungibility::NonFungible(AssetInstance::Index(1)),
+		};
+	} : {
+		T::DropAssets::drop_assets(
+			&origin,
+			asset.into(),
+			&XcmContext {
+				origin: Some(origin),
+				message_id: [0; 32],
+				topic: None,
+			},
+		);
+	}
+
 	drop_assets_default {
 		let origin = MultiLocation::default();
 		let asset = MultiAsset { id: XcmAssetId::Abstract(Default::default()), fun: Fungibility::Fungible(0) };
@@ -53,6 +53,9 @@ pub mod pallet {
 
 		/// Handler to register an asset.
 		fn register_asset(asset_id: Self::AssetId, location: MultiLocation);
+
+		/// Handler to register a non-fungible collection whose items are trapped.
+		fn register_collection(location: MultiLocation);
 	}
 
 	#[pallet::pallet]
@@ -0,0 +1,44 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the withdraw and teleport pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_withdraw_teleport::DryRunEffects;
+use sp_runtime::DispatchError;
+use xcm::{v3::WeightLimit, VersionedMultiAssets, VersionedMultiLocation};
+
+sp_api::decl_runtime_apis! {
+	/// API to quote and dry-run the teleports of `pallet-withdraw-teleport`.
+	pub trait WithdrawTeleportApi {
+		/// Dry-run `withdraw_and_teleport` for `origin`, or `limited_withdraw_and_teleport` if
+		/// `weight_limit` is set.
+		///
+		/// The local message is executed and rolled back, returning its outcome, its weight, the
+		/// message that would be sent and the fee it would pay on destination.
+		fn dry_run_withdraw_and_teleport(
+			origin: VersionedMultiLocation,
+			dest: VersionedMultiLocation,
+			beneficiary: VersionedMultiLocation,
+			assets: VersionedMultiAssets,
+			fee_assets: VersionedMultiAssets,
+			fee_asset_item: u32,
+			weight_limit: Option<WeightLimit>,
+		) -> Result<DryRunEffects, DispatchError>;
+	}
+}
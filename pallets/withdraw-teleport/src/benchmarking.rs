@@ -24,8 +24,10 @@ mod benchmarks {
 			.into();
 		let versioned_beneficiary: VersionedMultiLocation =
 			AccountId32 { network: None, id: recipient.into() }.into();
-		let versioned_assets: VersionedMultiAssets = asset.into();
+		let versioned_fee_assets: VersionedMultiAssets = asset.into();
 		let amount: u32 = 1_000;
+		let teleported: MultiAsset = (MultiLocation::new(0, Here), amount as u128).into();
+		let versioned_assets: VersionedMultiAssets = teleported.into();
 		let caller = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, 100_000_000u32.into());
 		let initial_balance = T::Currency::free_balance(&caller);
@@ -35,8 +37,9 @@ mod benchmarks {
 			RawOrigin::Signed(caller.clone()),
 			Box::new(versioned_dest),
 			Box::new(versioned_beneficiary),
-			amount.into(),
 			Box::new(versioned_assets),
+			Box::new(versioned_fee_assets),
+			0,
 		);
 
 		let remaining_balance = initial_balance - amount.into() - (fee_amount as u32).into();
@@ -45,5 +48,65 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn notify_teleport() -> Result<(), BenchmarkError> {
+		let responder_origin =
+			T::ResponseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let responder = T::ResponseOrigin::ensure_origin(responder_origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let caller = whitelisted_caller();
+		let origin = T::ExecuteXcmOrigin::ensure_origin(RawOrigin::Signed(caller.clone()).into())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let amount: u32 = 1_000;
+		T::Currency::make_free_balance_be(&caller, 100_000_000u32.into());
+		let initial_balance = T::Currency::free_balance(&caller);
+		PendingTeleports::<T>::insert(
+			0,
+			PendingTeleport {
+				origin,
+				destination: responder,
+				assets: MultiAsset::from((MultiLocation::new(0, Here), amount as u128)).into(),
+				timeout: 1u32.into(),
+			},
+		);
+
+		// A failed teleport, refunding the native asset, is the worst case.
+		#[extrinsic_call]
+		_(responder_origin, 0, Response::ExecutionResult(Some((0, XcmError::Unimplemented))));
+
+		assert_eq!(T::Currency::free_balance(&caller), initial_balance + amount.into());
+		assert!(PendingTeleports::<T>::get(0).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_teleport() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller();
+		let origin = T::ExecuteXcmOrigin::ensure_origin(RawOrigin::Signed(caller.clone()).into())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let amount: u32 = 1_000;
+		T::Currency::make_free_balance_be(&caller, 100_000_000u32.into());
+		let initial_balance = T::Currency::free_balance(&caller);
+		PendingTeleports::<T>::insert(
+			0,
+			PendingTeleport {
+				origin,
+				destination: MultiLocation::parent(),
+				assets: MultiAsset::from((MultiLocation::new(0, Here), amount as u128)).into(),
+				timeout: 1u32.into(),
+			},
+		);
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+		let expire_origin =
+			T::ExpireOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(expire_origin, 0);
+
+		assert_eq!(T::Currency::free_balance(&caller), initial_balance + amount.into());
+		assert!(PendingTeleports::<T>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(WithdrawTeleport, crate::mock::new_test_ext(), crate::mock::Test);
 }
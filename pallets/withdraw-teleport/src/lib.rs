@@ -25,30 +25,151 @@ type BaseXcm<T> = pallet_xcm::Pallet<T>;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{Contains, EnsureOrigin, Get},
+	weights::constants::{WEIGHT_PROOF_SIZE_PER_MB, WEIGHT_REF_TIME_PER_SECOND},
 };
-use frame_system::pallet_prelude::OriginFor;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 pub use pallet::*;
 use pallet_xcm::WeightInfo as RuntimeXcmWeightInfo;
-use parity_scale_codec::Encode;
-use sp_std::{boxed::Box, vec};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, DispatchError, RuntimeDebug};
+use sp_std::{boxed::Box, marker::PhantomData, vec, vec::Vec};
 pub use xcm::{
-	latest::prelude::*, VersionedMultiAssets, VersionedMultiLocation, VersionedResponse,
-	VersionedXcm,
+	latest::prelude::*, VersionedMultiAsset, VersionedMultiAssets, VersionedMultiLocation,
+	VersionedResponse, VersionedXcm,
 };
-use xcm_executor::traits::WeightBounds;
+use xcm_executor::traits::{TransactAsset, WeightBounds};
 
 #[cfg(test)]
 mod mock;
 
-// #[cfg(test)]
-// mod tests;
+#[cfg(test)]
+mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+/// Weighs the messages sent to a destination, as they will be executed there.
+pub trait DestinationWeigher {
+	/// The weight of `message` on `dest`, if it is known.
+	fn weigh(dest: &MultiLocation, message: &Xcm<()>) -> Option<Weight>;
+}
+
+impl DestinationWeigher for () {
+	fn weigh(_dest: &MultiLocation, _message: &Xcm<()>) -> Option<Weight> {
+		None
+	}
+}
+
+/// Weighs the messages sent to the destinations listed by `UnitWeights` at a fixed weight per
+/// instruction, as `FixedWeightBounds` does.
+///
+/// The instructions of `SetAppendix` and `SetErrorHandler` are counted on top of the instruction
+/// holding them.
+pub struct FixedWeightPerInstruction<UnitWeights>(PhantomData<UnitWeights>);
+impl<UnitWeights> FixedWeightPerInstruction<UnitWeights> {
+	fn instructions(message: &Xcm<()>) -> u64 {
+		message.0.iter().fold(0u64, |count, instruction| {
+			let nested = match instruction {
+				SetAppendix(xcm) | SetErrorHandler(xcm) => Self::instructions(xcm),
+				_ => 0,
+			};
+			count.saturating_add(1).saturating_add(nested)
+		})
+	}
+}
+impl<UnitWeights: Get<Vec<(MultiLocation, Weight)>>> DestinationWeigher
+	for FixedWeightPerInstruction<UnitWeights>
+{
+	fn weigh(dest: &MultiLocation, message: &Xcm<()>) -> Option<Weight> {
+		let (_, unit_weight) =
+			UnitWeights::get().into_iter().find(|(location, _)| location == dest)?;
+		unit_weight.checked_mul(Self::instructions(message))
+	}
+}
+
+/// Quotes the fees charged on a destination for the execution of messages.
+pub trait DestinationFees {
+	/// The amount of `asset` charged on `dest` for `weight`, if it is known.
+	fn quote(dest: &MultiLocation, asset: &AssetId, weight: Weight) -> Option<u128>;
+}
+
+impl DestinationFees for () {
+	fn quote(_dest: &MultiLocation, _asset: &AssetId, _weight: Weight) -> Option<u128> {
+		None
+	}
+}
+
+/// Quotes the fees charged on the destinations listed by `Rates` in units of an asset per second
+/// of execution and per megabyte of proof, as `FixedRateOfFungible` charges them.
+pub struct FixedDestinationRates<Rates>(PhantomData<Rates>);
+impl<Rates: Get<Vec<(MultiLocation, AssetId, u128, u128)>>> DestinationFees
+	for FixedDestinationRates<Rates>
+{
+	fn quote(dest: &MultiLocation, asset: &AssetId, weight: Weight) -> Option<u128> {
+		let (_, _, units_per_second, units_per_mb) = Rates::get()
+			.into_iter()
+			.find(|(location, id, _, _)| location == dest && id == asset)?;
+		Some(
+			units_per_second.saturating_mul(weight.ref_time() as u128)
+				/ (WEIGHT_REF_TIME_PER_SECOND as u128)
+				+ units_per_mb.saturating_mul(weight.proof_size() as u128)
+					/ (WEIGHT_PROOF_SIZE_PER_MB as u128),
+		)
+	}
+}
+
+/// Effects of a `withdraw_and_teleport` call, dry-run without being dispatched.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct DryRunEffects {
+	/// Outcome of the execution of the local message, withdrawing and burning the assets.
+	pub outcome: Outcome,
+	/// The message that would be sent to destination.
+	pub message: VersionedXcm<()>,
+	/// The weight of the local message.
+	pub local_weight: Weight,
+	/// The execution bought on destination.
+	pub destination_weight: WeightLimit,
+	/// The fee asset paying for `destination_weight`, if `DestinationFees` can quote it.
+	pub destination_fee: Option<VersionedMultiAsset>,
+}
+
+/// A teleport ready to be executed locally and sent to destination.
+struct PreparedTeleport<Call> {
+	origin: MultiLocation,
+	dest: MultiLocation,
+	local_message: Xcm<Call>,
+	local_weight: Weight,
+	message: Xcm<()>,
+	weight_limit: WeightLimit,
+	fee_asset: AssetId,
+}
+
+/// Maximum number of assets teleported, and of fee assets, in a single call.
+const MAX_ASSETS_FOR_TELEPORT: usize = 8;
+
+/// Index of the instruction depositing the teleported assets in the message sent to destination.
+/// Errors reported for earlier instructions happen before any asset is deposited, errors reported
+/// for later instructions once the teleport is complete.
+const TELEPORT_DEPOSIT_INDEX: u32 = 4;
+
+/// A teleport sent to destination and waiting for its outcome to be reported.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTeleport<BlockNumber> {
+	/// The origin the assets were withdrawn from, refunded if the teleport fails.
+	pub origin: MultiLocation,
+	/// The destination of the teleport, expected to report its outcome.
+	pub destination: MultiLocation,
+	/// The assets teleported, from the local perspective.
+	pub assets: MultiAssets,
+	/// The block after which the teleport can be expired if its outcome was not reported.
+	pub timeout: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -59,15 +180,65 @@ pub mod pallet {
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_xcm::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Estimates the weight to buy on destination when the caller does not limit it.
+		type DestinationWeigher: DestinationWeigher;
+
+		/// Quotes the fees charged on destination, for dry-runs.
+		type DestinationFees: DestinationFees;
+
+		/// The overarching call type, used to be notified of the outcome of a teleport.
+		type RuntimeCall: From<Call<Self>> + Into<<Self as pallet_xcm::Config>::RuntimeCall>;
+
+		/// Origin of the responses to the queries of teleport outcomes, e.g.
+		/// `pallet_xcm::EnsureResponse`.
+		type ResponseOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = MultiLocation,
+		>;
+
+		/// Deposits the teleported assets back to their origin when a teleport fails.
+		type AssetTransactor: TransactAsset;
+
+		/// Number of blocks the outcome of a teleport is waited for.
+		#[pallet::constant]
+		type ResponseTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Destinations trusted to report the outcome of teleports. Only teleports to these
+		/// destinations are refunded when they fail or time out.
+		type TrustedDestinations: Contains<MultiLocation>;
+
+		/// Origin allowed to refund the teleports whose outcome was not reported before they
+		/// timed out. A delayed teleport may still land on destination, so this origin must check
+		/// it was not executed there before refunding it.
+		type ExpireOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		type WeightInfo: WeightInfo;
 	}
 
+	/// Teleports waiting for the destination to report their outcome, by query id.
+	#[pallet::storage]
+	pub type PendingTeleports<T: Config> =
+		StorageMap<_, Twox64Concat, QueryId, PendingTeleport<BlockNumberFor<T>>, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// An error ocured during send
 		SendError,
 		/// Failed to execute
 		FailedToExecuteXcm,
+		/// The weight of the message on destination cannot be estimated
+		CannotWeighOnDestination,
+		/// The weight limit is below the estimated weight of the message on destination
+		WeightLimitTooLow,
+		/// No teleport is waiting for the outcome reported
+		UnknownTeleport,
+		/// The response is not the outcome of a teleport
+		UnexpectedResponse,
+		/// The teleport is still waiting for its outcome
+		TeleportNotExpired,
+		/// `fee_asset_item` is not the index of one of the fee assets
+		FeeAssetNotFound,
 	}
 
 	#[pallet::event]
@@ -82,117 +253,358 @@ pub mod pallet {
 			message: Xcm<()>,
 			message_id: XcmHash,
 		},
+		/// The destination reported the teleport succeeded.
+		TeleportCompleted { query_id: QueryId },
+		/// The destination reported the teleport failed, the assets were refunded to origin.
+		TeleportFailed {
+			query_id: QueryId,
+			origin: MultiLocation,
+			assets: MultiAssets,
+			error: XcmError,
+		},
+		/// The destination failed to deposit the teleported assets, those not deposited are
+		/// trapped on destination.
+		TeleportIncomplete { query_id: QueryId, error: XcmError },
+		/// No outcome was reported before the teleport timed out, the assets were refunded to
+		/// origin by `ExpireOrigin`.
+		TeleportExpired { query_id: QueryId, origin: MultiLocation, assets: MultiAssets },
 	}
 
-	/// Teleport native asset from a parachain to another.
-	/// This function is called by the parachain that wants to teleport native assets to another
+	/// Teleport assets from a parachain to another.
+	/// This function is called by the parachain that wants to teleport assets to another
 	/// parachain but needs to buy execution on the destination parachain with an asset that is not
 	/// being teleported. We call this asset the fee asset.
-	/// The parachain that wants to teleport native assets to another parachain with this method
-	/// need to fund its Sovereign Account with the fee asset on the destination parachain.
-	/// If multiple fee assets are included in the message, only the first one is used to buy
-	/// execution. Fee assets are trapped on the destination parachain.
+	/// The parachain that wants to teleport assets to another parachain with this method
+	/// need to fund its Sovereign Account with the fee assets on the destination parachain.
+	/// Only the fee asset at `fee_asset_item` is used to buy execution, what is left of the fee
+	/// assets is deposited to the beneficiary.
 	/// Parameters:
 	/// - `origin`: The origin of the call.
 	/// - `dest`: The destination chain of the teleport.
 	/// - `beneficiary`: The beneficiary of the teleport from the perspective of the destination
 	///   chain.
-	/// - `native_asset_amount`: The amount of native asset to teleport.
-	/// - `fee_asset`: The fee asset to buy execution on the destination chain.
+	/// - `assets`: The assets to teleport, each of them must pass the `XcmTeleportFilter`.
+	/// - `fee_assets`: The fee assets withdrawn on the destination chain.
+	/// - `fee_asset_item`: The index into `fee_assets` of the asset to buy execution with.
+	///
+	/// The execution bought on destination is limited to the weight of the message estimated by
+	/// `DestinationWeigher`, see `limited_withdraw_and_teleport` to set the limit.
+	///
+	/// Destinations in `TrustedDestinations` report the outcome of the teleport, the teleported
+	/// assets are refunded if it fails. `ExpireOrigin` may refund them if no outcome is reported
+	/// before `ResponseTimeout`.
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight({
-			let native_asset = MultiAsset {
-				id: AssetId::Concrete(MultiLocation::here()),
-				fun: Fungibility::Fungible(*native_asset_amount),
-			};
-			let native_assets = MultiAssets::from(vec![native_asset.clone()]);
-			let maybe_assets: Result<MultiAssets, ()> = (*fee_asset.clone()).try_into();
-			let send_weight = <T as pallet_xcm::Config>::WeightInfo::send();
-			match maybe_assets {
-				Ok(assets) => {
-					use sp_std::vec;
-					let mut message = Xcm(vec![
-						WithdrawAsset(native_assets.clone()),
-						SetFeesMode { jit_withdraw: true },
-						BurnAsset(native_assets),
-						WithdrawAsset(assets.clone()),
-						BurnAsset(assets),
-					]);
-					T::Weigher::weight(&mut message).map_or(Weight::MAX, |w| <T as pallet::Config>::WeightInfo::withdraw_and_teleport().saturating_add(w).saturating_add(send_weight))
-				}
-				_ => Weight::MAX,
-			}
-		})]
+		#[pallet::weight(Pallet::<T>::withdraw_and_teleport_weight(assets, fee_assets))]
 		pub fn withdraw_and_teleport(
 			origin: OriginFor<T>,
 			dest: Box<VersionedMultiLocation>,
 			beneficiary: Box<VersionedMultiLocation>,
-			native_asset_amount: u128,
-			fee_asset: Box<VersionedMultiAssets>,
+			assets: Box<VersionedMultiAssets>,
+			fee_assets: Box<VersionedMultiAssets>,
+			fee_asset_item: u32,
+		) -> DispatchResult {
+			Self::do_withdraw_and_teleport(
+				origin,
+				dest,
+				beneficiary,
+				assets,
+				fee_assets,
+				fee_asset_item,
+				None,
+			)
+		}
+
+		/// Teleport assets from a parachain to another, like `withdraw_and_teleport`, buying
+		/// execution on destination up to `weight_limit`.
+		///
+		/// `weight_limit` may not be lower than the weight estimated by `DestinationWeigher`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(Pallet::<T>::withdraw_and_teleport_weight(assets, fee_assets))]
+		pub fn limited_withdraw_and_teleport(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			beneficiary: Box<VersionedMultiLocation>,
+			assets: Box<VersionedMultiAssets>,
+			fee_assets: Box<VersionedMultiAssets>,
+			fee_asset_item: u32,
+			weight_limit: WeightLimit,
 		) -> DispatchResult {
 			Self::do_withdraw_and_teleport(
 				origin,
 				dest,
 				beneficiary,
-				native_asset_amount,
-				fee_asset,
+				assets,
+				fee_assets,
+				fee_asset_item,
+				Some(weight_limit),
 			)
 		}
+
+		/// Handle the outcome of a teleport reported by its destination, refunding the teleported
+		/// assets to origin if the teleport failed before any of them was deposited.
+		///
+		/// The fee assets are not refunded: they stay trapped on destination if the message failed.
+		///
+		/// Dispatched by `pallet-xcm` when the response to the query is received.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::notify_teleport())]
+		pub fn notify_teleport(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResult {
+			let responder = T::ResponseOrigin::ensure_origin(origin)?;
+			let teleport =
+				PendingTeleports::<T>::get(query_id).ok_or(Error::<T>::UnknownTeleport)?;
+			ensure!(responder == teleport.destination, DispatchError::BadOrigin);
+
+			match response {
+				Response::ExecutionResult(Some((index, error)))
+					if index < TELEPORT_DEPOSIT_INDEX =>
+				{
+					Self::refund(&teleport)?;
+					Self::deposit_event(Event::TeleportFailed {
+						query_id,
+						origin: teleport.origin,
+						assets: teleport.assets,
+						error,
+					});
+				},
+				// some of the assets may have been deposited, refunding them would duplicate them
+				Response::ExecutionResult(Some((TELEPORT_DEPOSIT_INDEX, error))) => {
+					Self::deposit_event(Event::TeleportIncomplete { query_id, error })
+				},
+				Response::ExecutionResult(_) => {
+					Self::deposit_event(Event::TeleportCompleted { query_id })
+				},
+				_ => return Err(Error::<T>::UnexpectedResponse.into()),
+			}
+			PendingTeleports::<T>::remove(query_id);
+			Ok(())
+		}
+
+		/// Refund the teleported assets to origin when the destination did not report the outcome
+		/// of the teleport before it timed out, e.g. because the message failed before its
+		/// outcome could be reported.
+		///
+		/// Only `ExpireOrigin` may call it, once `ResponseTimeout` blocks have passed since the
+		/// teleport: a delayed message may still be executed on destination, and refunding it
+		/// would then duplicate the teleported assets.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::expire_teleport())]
+		pub fn expire_teleport(origin: OriginFor<T>, query_id: QueryId) -> DispatchResult {
+			T::ExpireOrigin::ensure_origin(origin)?;
+			let teleport =
+				PendingTeleports::<T>::get(query_id).ok_or(Error::<T>::UnknownTeleport)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > teleport.timeout,
+				Error::<T>::TeleportNotExpired
+			);
+
+			Self::refund(&teleport)?;
+			PendingTeleports::<T>::remove(query_id);
+			Self::deposit_event(Event::TeleportExpired {
+				query_id,
+				origin: teleport.origin,
+				assets: teleport.assets,
+			});
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Deposit the assets of a teleport back to its origin.
+	fn refund(teleport: &PendingTeleport<BlockNumberFor<T>>) -> DispatchResult {
+		for asset in teleport.assets.inner() {
+			T::AssetTransactor::deposit_asset(asset, &teleport.origin, None)
+				.map_err(|_| Error::<T>::FailedToExecuteXcm)?;
+		}
+		Ok(())
+	}
+
+	fn withdraw_and_teleport_weight(
+		assets: &VersionedMultiAssets,
+		fee_assets: &VersionedMultiAssets,
+	) -> Weight {
+		let maybe_assets: Result<MultiAssets, ()> = assets.clone().try_into();
+		let maybe_fee_assets: Result<MultiAssets, ()> = fee_assets.clone().try_into();
+		let send_weight = <T as pallet_xcm::Config>::WeightInfo::send();
+		match (maybe_assets, maybe_fee_assets) {
+			(Ok(assets), Ok(fee_assets)) => {
+				let count = assets.len().max(1) as u64;
+				let mut message = Xcm(vec![
+					WithdrawAsset(assets.clone()),
+					SetFeesMode { jit_withdraw: true },
+					BurnAsset(assets),
+					WithdrawAsset(fee_assets.clone()),
+					BurnAsset(fee_assets),
+				]);
+				T::Weigher::weight(&mut message).map_or(Weight::MAX, |w| {
+					<T as pallet::Config>::WeightInfo::withdraw_and_teleport()
+						.saturating_mul(count)
+						.saturating_add(w)
+						.saturating_add(send_weight)
+				})
+			},
+			_ => Weight::MAX,
+		}
+	}
+
+	/// Dry-run `withdraw_and_teleport` for `origin`, executing the local message in a storage
+	/// transaction rolled back afterwards. Nothing is sent to destination.
+	///
+	/// `weight_limit` is the one of `limited_withdraw_and_teleport`, `None` for
+	/// `withdraw_and_teleport`.
+	pub fn dry_run_withdraw_and_teleport(
+		origin: VersionedMultiLocation,
+		dest: VersionedMultiLocation,
+		beneficiary: VersionedMultiLocation,
+		assets: VersionedMultiAssets,
+		fee_assets: VersionedMultiAssets,
+		fee_asset_item: u32,
+		weight_limit: Option<WeightLimit>,
+	) -> Result<DryRunEffects, DispatchError> {
+		let origin: MultiLocation =
+			origin.try_into().map_err(|()| pallet_xcm::Error::<T>::BadVersion)?;
+		with_transaction(|| {
+			let effects = Self::prepare_withdraw_and_teleport(
+				origin,
+				dest,
+				beneficiary,
+				assets,
+				fee_assets,
+				fee_asset_item,
+				weight_limit,
+			)
+			.map(|prepared| {
+				let hash = prepared.local_message.using_encoded(sp_io::hashing::blake2_256);
+				let outcome = T::XcmExecutor::execute_xcm_in_credit(
+					prepared.origin,
+					prepared.local_message,
+					hash,
+					prepared.local_weight,
+					prepared.local_weight,
+				);
+				let destination_fee = match prepared.weight_limit {
+					Limited(weight) => {
+						T::DestinationFees::quote(&prepared.dest, &prepared.fee_asset, weight)
+					},
+					Unlimited => None,
+				}
+				.map(|amount| MultiAsset::from((prepared.fee_asset, amount)).into());
+				DryRunEffects {
+					outcome,
+					message: VersionedXcm::from(prepared.message),
+					local_weight: prepared.local_weight,
+					destination_weight: prepared.weight_limit,
+					destination_fee,
+				}
+			});
+			TransactionOutcome::Rollback(effects)
+		})
+	}
+
 	fn do_withdraw_and_teleport(
 		origin: OriginFor<T>,
 		dest: Box<VersionedMultiLocation>,
 		beneficiary: Box<VersionedMultiLocation>,
-		native_asset_amount: u128,
-		fee_asset: Box<VersionedMultiAssets>,
+		assets: Box<VersionedMultiAssets>,
+		fee_assets: Box<VersionedMultiAssets>,
+		fee_asset_item: u32,
+		weight_limit: Option<WeightLimit>,
 	) -> DispatchResult {
-		//Unbox origin, destination and beneficiary.
 		let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
-		let dest: MultiLocation =
-			(*dest).try_into().map_err(|()| pallet_xcm::Error::<T>::BadVersion)?;
-		let beneficiary: MultiLocation =
-			(*beneficiary).try_into().map_err(|()| pallet_xcm::Error::<T>::BadVersion)?;
-		//Unbox fee asset
-		let fee_asset: MultiAssets =
-			(*fee_asset).try_into().map_err(|()| pallet_xcm::Error::<T>::BadVersion)?;
+		let PreparedTeleport { origin, dest, local_message, local_weight, message, .. } =
+			Self::prepare_withdraw_and_teleport(
+				origin_location,
+				*dest,
+				*beneficiary,
+				*assets,
+				*fee_assets,
+				fee_asset_item,
+				weight_limit,
+			)?;
+
+		// Execute Withdraw for trapping assets on origin.
+		let hash = local_message.using_encoded(sp_io::hashing::blake2_256);
+		let outcome = T::XcmExecutor::execute_xcm_in_credit(
+			origin,
+			local_message,
+			hash,
+			local_weight,
+			local_weight,
+		);
+		outcome.clone().ensure_complete().map_err(|e| {
+			log::debug!("{e:?}");
+			Error::<T>::FailedToExecuteXcm
+		})?;
+		Self::deposit_event(Event::Attempted { outcome });
 
-		// Limit the number of fee assets to 1.
-		ensure!(fee_asset.len() > 0, pallet_xcm::Error::<T>::Empty);
-		ensure!(fee_asset.len() < 2, pallet_xcm::Error::<T>::TooManyAssets);
+		// Use pallet-xcm send for sending message.
+		// Origin is set to Root so it is interpreted as Sovereign Account.
+		let root_origin = T::SendXcmOrigin::ensure_origin(frame_system::RawOrigin::Root.into())?;
+		let interior: Junctions =
+			root_origin.try_into().map_err(|_| pallet_xcm::Error::<T>::InvalidOrigin)?;
+		//TODO: Check this Error population
+		let message_id = BaseXcm::<T>::send_xcm(interior, dest, message.clone())
+			.map_err(|_| Error::<T>::SendError)?;
+		let e = Event::Sent { origin, destination: dest, message, message_id };
+		Self::deposit_event(e);
 
-		//Create assets
+		// Finish.
+		Ok(())
+	}
 
-		// Native from local perspective
-		let native_asset = MultiAsset {
-			id: AssetId::Concrete(MultiLocation::here()),
-			fun: Fungibility::Fungible(native_asset_amount),
-		};
-		let assets = MultiAssets::from(vec![native_asset.clone()]);
+	/// Build the message executed locally and the one sent to destination, registering the query
+	/// of the teleport outcome.
+	fn prepare_withdraw_and_teleport(
+		origin_location: MultiLocation,
+		dest: VersionedMultiLocation,
+		beneficiary: VersionedMultiLocation,
+		assets: VersionedMultiAssets,
+		fee_assets: VersionedMultiAssets,
+		fee_asset_item: u32,
+		weight_limit: Option<WeightLimit>,
+	) -> Result<PreparedTeleport<<T as pallet_xcm::Config>::RuntimeCall>, DispatchError> {
+		//Unbox destination and beneficiary.
+		let dest: MultiLocation =
+			dest.try_into().map_err(|()| pallet_xcm::Error::<T>::BadVersion)?;
+		let beneficiary: MultiLocation =
+			beneficiary.try_into().map_err(|()| pallet_xcm::Error::<T>::BadVersion)?;
+		//Unbox assets and fee assets
+		let assets: MultiAssets =
+			assets.try_into().map_err(|()| pallet_xcm::Error::<T>::BadVersion)?;
+		let fee_assets: MultiAssets =
+			fee_assets.try_into().map_err(|()| pallet_xcm::Error::<T>::BadVersion)?;
+		ensure!(!assets.is_none(), pallet_xcm::Error::<T>::Empty);
+		ensure!(!fee_assets.is_none(), pallet_xcm::Error::<T>::Empty);
+		ensure!(assets.len() <= MAX_ASSETS_FOR_TELEPORT, pallet_xcm::Error::<T>::TooManyAssets);
+		ensure!(fee_assets.len() <= MAX_ASSETS_FOR_TELEPORT, pallet_xcm::Error::<T>::TooManyAssets);
 
-		// Native from foreign perspective
+		// TeleportFilter check, for each asset.
+		for asset in assets.inner() {
+			let value = (origin_location, vec![asset.clone()]);
+			ensure!(T::XcmTeleportFilter::contains(&value), pallet_xcm::Error::<T>::Filtered);
+		}
+
+		// Assets from foreign perspective
 		let context = T::UniversalLocation::get();
-		let native_as_foreign = native_asset
+		let foreign_assets = assets
+			.clone()
 			.reanchored(&dest, context)
 			.map_err(|_| pallet_xcm::Error::<T>::CannotReanchor)?;
-		let foreign_assets = MultiAssets::from(vec![native_as_foreign]);
-
-		// TeleportFilter check
-		let value = (origin_location, assets.into_inner());
-		ensure!(T::XcmTeleportFilter::contains(&value), pallet_xcm::Error::<T>::Filtered);
-		let (origin_location, assets) = value;
 
 		// Reanchor the fee asset to the destination chain.
-		let fee_asset_item: usize = 0;
+		let fee_asset = fee_assets
+			.get(fee_asset_item as usize)
+			.ok_or(Error::<T>::FeeAssetNotFound)?
+			.clone();
+		let fee_asset_id = fee_asset.id;
 		let fees = fee_asset
-			.get(fee_asset_item)
-			.ok_or(pallet_xcm::Error::<T>::Empty)?
-			.clone()
 			.reanchored(&dest, context)
 			.map_err(|_| pallet_xcm::Error::<T>::CannotReanchor)?;
 
@@ -206,66 +618,95 @@ impl<T: Config> Pallet<T> {
 		// - Asset Hub does not recognize Sibling chains as trusted teleporters of ROC.
 
 		//Build the message to execute on origin.
-		let assets: MultiAssets = assets.into();
 		let mut message = Xcm(vec![
 			WithdrawAsset(assets.clone()),
 			SetFeesMode { jit_withdraw: true },
-			// Burn the native asset.
-			BurnAsset(assets),
-			// Burn the fee asset derivative.
-			WithdrawAsset(fee_asset.clone()),
-			BurnAsset(fee_asset.clone()),
+			// Burn the teleported assets.
+			BurnAsset(assets.clone()),
+			// Burn the fee assets derivatives.
+			WithdrawAsset(fee_assets.clone()),
+			BurnAsset(fee_assets.clone()),
 		]);
 
+		// Query the outcome of the message sent to a trusted destination, to refund the
+		// teleported assets if it fails.
+		let report_outcome = if T::TrustedDestinations::contains(&dest) {
+			let notify: <T as Config>::RuntimeCall =
+				Call::<T>::notify_teleport { query_id: 0, response: Response::Null }.into();
+			let timeout =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ResponseTimeout::get());
+			let query_id = BaseXcm::<T>::new_notify_query(dest, notify, timeout, Here);
+			let response_info = QueryResponseInfo {
+				destination: context
+					.invert_target(&dest)
+					.map_err(|()| pallet_xcm::Error::<T>::DestinationNotInvertible)?,
+				query_id,
+				max_weight: <T as Config>::WeightInfo::notify_teleport(),
+			};
+			PendingTeleports::<T>::insert(
+				query_id,
+				PendingTeleport { origin: origin_location, destination: dest, assets, timeout },
+			);
+			Some(SetAppendix(Xcm(vec![ReportError(response_info)])))
+		} else {
+			None
+		};
+
 		// Build the message to send to be executed.
-		// Set WeightLimit
-		// TODO: Implement weight_limit calculation with final instructions.
-		let weight_limit: WeightLimit = Unlimited;
-		let fee_asset_id: AssetId = fee_asset.get(0).ok_or(pallet_xcm::Error::<T>::Empty)?.id;
-		let xcm_to_send: Xcm<()> = Xcm(vec![
-			// User must have the derivative of fee_asset on origin.
-			WithdrawAsset(fee_asset.clone()),
-			BuyExecution { fees, weight_limit },
-			ReceiveTeleportedAsset(foreign_assets.clone()),
-			// We can deposit funds since they were both withdrawn on origin.
-			DepositAsset { assets: MultiAssetFilter::Definite(foreign_assets), beneficiary },
-			RefundSurplus,
-			DepositAsset {
-				assets: Wild(AllOf { id: fee_asset_id, fun: WildFungibility::Fungible }),
-				beneficiary,
+		let fee_assets_count = fee_assets.len() as u32;
+		let message_to_send = |weight_limit| -> Xcm<()> {
+			let mut message = vec![
+				// User must have the derivatives of fee_assets on origin.
+				WithdrawAsset(fee_assets.clone()),
+				BuyExecution { fees: fees.clone(), weight_limit },
+			];
+			// Report the outcome of the message back to origin. This comes after paying for
+			// execution, as barriers only let messages starting with the payment through: if
+			// paying fails, nothing is reported and only `ExpireOrigin` can refund the teleport.
+			message.extend(report_outcome.clone());
+			message.extend([
+				ReceiveTeleportedAsset(foreign_assets.clone()),
+				// We can deposit funds since they were both withdrawn on origin.
+				DepositAsset {
+					assets: MultiAssetFilter::Definite(foreign_assets.clone()),
+					beneficiary,
+				},
+				RefundSurplus,
+				DepositAsset { assets: Wild(AllCounted(fee_assets_count)), beneficiary },
+			]);
+			Xcm(message)
+		};
+
+		// Set WeightLimit, to the estimated weight of the message on destination unless the caller
+		// sets a higher one.
+		let estimate = T::DestinationWeigher::weigh(&dest, &message_to_send(Unlimited));
+		let weight_limit = match (weight_limit, estimate) {
+			(Some(Limited(limit)), Some(estimate)) => {
+				ensure!(estimate.all_lte(limit), Error::<T>::WeightLimitTooLow);
+				Limited(limit)
 			},
-		]);
+			(Some(weight_limit), _) => weight_limit,
+			(None, Some(estimate)) => Limited(estimate),
+			(None, None) => return Err(Error::<T>::CannotWeighOnDestination.into()),
+		};
+		let xcm_to_send = message_to_send(weight_limit);
 
-		let weight = T::Weigher::weight(&mut message)
-			.map_err(|()| pallet_xcm::Error::<T>::UnweighableMessage)?;
+		// Check the message can be delivered before burning anything, so it fails early instead
+		// of trapping the fee asset on destination.
+		validate_send::<<T as pallet_xcm::Config>::XcmRouter>(dest, xcm_to_send.clone())
+			.map_err(|_| Error::<T>::SendError)?;
 
-		// Execute Withdraw for trapping assets on origin.
-		let hash = message.using_encoded(sp_io::hashing::blake2_256);
-		let outcome =
-			T::XcmExecutor::execute_xcm_in_credit(origin_location, message, hash, weight, weight);
-		outcome.clone().ensure_complete().map_err(|e| {
-			log::debug!("{e:?}");
-			Error::<T>::FailedToExecuteXcm
-		})?;
-		Self::deposit_event(Event::Attempted { outcome });
+		let local_weight = T::Weigher::weight(&mut message)
+			.map_err(|()| pallet_xcm::Error::<T>::UnweighableMessage)?;
 
-		// Use pallet-xcm send for sending message.
-		// Origin is set to Root so it is interpreted as Sovereign Account.
-		let root_origin = T::SendXcmOrigin::ensure_origin(frame_system::RawOrigin::Root.into())?;
-		let interior: Junctions =
-			root_origin.try_into().map_err(|_| pallet_xcm::Error::<T>::InvalidOrigin)?;
-		//TODO: Check this Error population
-		let message_id = BaseXcm::<T>::send_xcm(interior, dest, xcm_to_send.clone())
-			.map_err(|_| Error::<T>::SendError)?;
-		let e = Event::Sent {
+		Ok(PreparedTeleport {
 			origin: origin_location,
-			destination: dest,
+			dest,
+			local_message: message,
+			local_weight,
 			message: xcm_to_send,
-			message_id,
-		};
-		Self::deposit_event(e);
-
-		// Finish.
-		Ok(())
+			weight_limit,
+			fee_asset: fee_asset_id,
+		})
 	}
 }
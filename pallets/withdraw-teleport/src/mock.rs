@@ -1,30 +1,41 @@
 use crate as pallet_withdraw_teleport;
-use frame_benchmarking::account;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU128, ConstU16, ConstU32, ConstU64, Everything, Nothing},
+	traits::{
+		AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, Contains, Equals,
+		Everything, Nothing,
+	},
 	weights::Weight,
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_xcm::*;
 use parity_scale_codec::Encode;
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use polkadot_runtime_parachains::origin;
 use sp_core::H256;
-use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage};
+use sp_runtime::{
+	traits::{IdentityLookup, MaybeEquivalence},
+	AccountId32, BuildStorage,
+};
 pub use sp_std::cell::RefCell;
 use xcm::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, Case, ChildParachainAsNative, ChildParachainConvertsVia,
 	ChildSystemParachainAsSuperuser, CurrencyAdapter as XcmCurrencyAdapter, FixedRateOfFungible,
-	FixedWeightBounds, IsConcrete, SignedAccountId32AsNative, SignedToAccountId32,
-	SovereignSignedViaLocation, TakeWeightCredit,
+	FixedWeightBounds, FungiblesAdapter, IsConcrete, MatchedConvertedConcreteId, NoChecking,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
 };
-use xcm_executor::XcmExecutor;
+use xcm_executor::{traits::JustTry, XcmExecutor};
 
 pub type AccountId = AccountId32;
 pub type Balance = u128;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const INITIAL_BALANCE: Balance = 100_000_000;
+/// The fungibles asset used to pay for execution on destination, the relay chain token.
+pub const FEE_ASSET_ID: u32 = 1;
+pub const PARA_ID: u32 = 2000;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 construct_runtime!(
@@ -32,10 +43,11 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Config<T>, Event<T>},
 		ParasOrigin: origin::{Pallet, Origin},
 		XcmPallet: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin, Config<T>},
 		//TestNotifier: pallet_test_notifier::{Pallet, Call, Event<T>},
-		WithdrawTeleport: pallet_withdraw_teleport::{Pallet, Call, Event<T>},
+		WithdrawTeleport: pallet_withdraw_teleport::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -138,18 +150,64 @@ impl pallet_balances::Config for Test {
 	type RuntimeFreezeReason = ();
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<5>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<10>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 parameter_types! {
-	pub const RelayLocation: MultiLocation = Here.into_location();
+	pub const SelfLocation: MultiLocation = Here.into_location();
+	pub const ParentLocation: MultiLocation = MultiLocation::parent();
 	pub const AnyNetwork: Option<NetworkId> = None;
-	pub UniversalLocation: InteriorMultiLocation = Here;
+	pub UniversalLocation: InteriorMultiLocation =
+		X2(GlobalConsensus(NetworkId::Rococo), Parachain(PARA_ID));
 	pub UnitWeightCost: u64 = 1_000;
+	pub CheckingAccount: AccountId = AccountId32::new([0u8; 32]);
 }
 
 pub type SovereignAccountOf =
 	(ChildParachainConvertsVia<ParaId, AccountId>, AccountId32Aliases<AnyNetwork, AccountId>);
 
 pub type LocalAssetTransactor =
-	XcmCurrencyAdapter<Balances, IsConcrete<RelayLocation>, SovereignAccountOf, AccountId, ()>;
+	XcmCurrencyAdapter<Balances, IsConcrete<SelfLocation>, SovereignAccountOf, AccountId, ()>;
+
+/// Converts the relay chain token into the fee asset.
+pub struct ParentAsFeeAsset;
+impl MaybeEquivalence<MultiLocation, u32> for ParentAsFeeAsset {
+	fn convert(location: &MultiLocation) -> Option<u32> {
+		(*location == ParentLocation::get()).then_some(FEE_ASSET_ID)
+	}
+	fn convert_back(id: &u32) -> Option<MultiLocation> {
+		(*id == FEE_ASSET_ID).then_some(ParentLocation::get())
+	}
+}
+
+pub type FeeAssetTransactor = FungiblesAdapter<
+	Assets,
+	MatchedConvertedConcreteId<u32, Balance, Everything, ParentAsFeeAsset, JustTry>,
+	SovereignAccountOf,
+	AccountId,
+	NoChecking,
+	CheckingAccount,
+>;
 
 type LocalOriginConverter = (
 	SovereignSignedViaLocation<SovereignAccountOf, RuntimeOrigin>,
@@ -160,7 +218,7 @@ type LocalOriginConverter = (
 
 parameter_types! {
 	pub const BaseXcmWeight: Weight = Weight::from_parts(1_000, 1_000);
-	pub CurrencyPerSecondPerByte: (AssetId, u128, u128) = (Concrete(RelayLocation::get()), 1, 1);
+	pub CurrencyPerSecondPerByte: (AssetId, u128, u128) = (Concrete(SelfLocation::get()), 1, 1);
 	pub TrustedAssets: (MultiAssetFilter, MultiLocation) = (All.into(), Here.into());
 	pub const MaxInstructions: u32 = 100;
 	pub const MaxAssetsIntoHolding: u32 = 64;
@@ -177,7 +235,7 @@ pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = TestSendXcm;
-	type AssetTransactor = LocalAssetTransactor;
+	type AssetTransactor = (LocalAssetTransactor, FeeAssetTransactor);
 	type OriginConverter = LocalOriginConverter;
 	type IsReserve = ();
 	type IsTeleporter = Case<TrustedAssets>;
@@ -203,6 +261,21 @@ impl xcm_executor::Config for XcmConfig {
 
 pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, AnyNetwork>;
 
+parameter_types! {
+	pub static NonTeleportableAssets: Vec<MultiLocation> = vec![];
+}
+
+/// Teleport filter rejecting the assets listed by `NonTeleportableAssets`.
+pub struct TestTeleportFilter;
+impl Contains<(MultiLocation, Vec<MultiAsset>)> for TestTeleportFilter {
+	fn contains((_, assets): &(MultiLocation, Vec<MultiAsset>)) -> bool {
+		assets.iter().all(|asset| match asset.id {
+			Concrete(location) => !NonTeleportableAssets::get().contains(&location),
+			Abstract(_) => true,
+		})
+	}
+}
+
 parameter_types! {
 	pub static AdvertisedXcmVersion: XcmVersion = 3;
 }
@@ -214,7 +287,7 @@ impl pallet_xcm::Config for Test {
 	type ExecuteXcmOrigin = xcm_builder::EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
 	type XcmExecuteFilter = Everything;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
-	type XcmTeleportFilter = Everything;
+	type XcmTeleportFilter = TestTeleportFilter;
 	type XcmReserveTransferFilter = Everything;
 	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
 	type UniversalLocation = UniversalLocation;
@@ -225,7 +298,7 @@ impl pallet_xcm::Config for Test {
 	type TrustedLockers = ();
 	type SovereignAccountOf = AccountId32Aliases<(), AccountId32>;
 	type Currency = Balances;
-	type CurrencyMatcher = IsConcrete<RelayLocation>;
+	type CurrencyMatcher = IsConcrete<SelfLocation>;
 	type MaxLockers = frame_support::traits::ConstU32<8>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
@@ -233,8 +306,23 @@ impl pallet_xcm::Config for Test {
 	type AdminOrigin = EnsureRoot<AccountId>;
 }
 
+parameter_types! {
+	pub DestinationUnitWeights: Vec<(MultiLocation, Weight)> =
+		vec![(MultiLocation::parent(), BaseXcmWeight::get())];
+	pub const ResponseTimeout: u64 = 100;
+}
+
 impl pallet_withdraw_teleport::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type DestinationWeigher =
+		pallet_withdraw_teleport::FixedWeightPerInstruction<DestinationUnitWeights>;
+	type DestinationFees = ();
+	type RuntimeCall = RuntimeCall;
+	type ResponseOrigin = EnsureResponse<Everything>;
+	type AssetTransactor = (LocalAssetTransactor, FeeAssetTransactor);
+	type ResponseTimeout = ResponseTimeout;
+	type TrustedDestinations = Equals<ParentLocation>;
+	type ExpireOrigin = frame_system::EnsureRoot<AccountId>;
 	type WeightInfo = pallet_withdraw_teleport::weights::SubstrateWeight<Test>;
 }
 
@@ -243,8 +331,13 @@ impl origin::Config for Test {}
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
-	pallet_balances::GenesisConfig::<Test> {
-		balances: vec![(account("Alice", 1, 1), 100_000_000)],
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(ALICE, INITIAL_BALANCE)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(FEE_ASSET_ID, ALICE, true, 1)],
+		metadata: vec![],
+		accounts: vec![(FEE_ASSET_ID, ALICE, INITIAL_BALANCE)],
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
@@ -0,0 +1,454 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResult,
+	traits::{Everything, Get},
+};
+use hex_literal::hex;
+use parity_scale_codec::Encode;
+use sp_runtime::DispatchError;
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_builder::AllowTopLevelPaidExecutionFrom;
+use xcm_executor::traits::{Properties, ShouldExecute};
+
+use crate::{mock::*, weights::WeightInfo, DestinationWeigher, Event, PendingTeleports};
+
+fn dest() -> MultiLocation {
+	MultiLocation::parent()
+}
+
+fn beneficiary() -> MultiLocation {
+	AccountId32 { network: None, id: [2u8; 32] }.into()
+}
+
+fn alice() -> MultiLocation {
+	AccountId32 { network: None, id: ALICE.into() }.into()
+}
+
+fn native(amount: u128) -> MultiAssets {
+	(Here, amount).into()
+}
+
+fn fee(amount: u128) -> MultiAssets {
+	(Parent, amount).into()
+}
+
+fn teleport(dest: MultiLocation, assets: MultiAssets, fee_assets: MultiAssets) -> DispatchResult {
+	WithdrawTeleport::withdraw_and_teleport(
+		RuntimeOrigin::signed(ALICE),
+		Box::new(dest.into()),
+		Box::new(beneficiary().into()),
+		Box::new(assets.into()),
+		Box::new(fee_assets.into()),
+		0,
+	)
+}
+
+fn respond(responder: MultiLocation, result: Option<(u32, XcmError)>) -> DispatchResult {
+	WithdrawTeleport::notify_teleport(
+		pallet_xcm::Origin::Response(responder).into(),
+		0,
+		Response::ExecutionResult(result),
+	)
+}
+
+#[test]
+fn teleport_burns_locally_and_sends_to_destination() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 1_000);
+		assert_eq!(Assets::balance(FEE_ASSET_ID, ALICE), INITIAL_BALANCE - 500);
+		assert!(PendingTeleports::<Test>::get(0).is_some());
+
+		// the native asset is reanchored to the relay chain, the fee asset is its own
+		let teleported: MultiAssets = (Parachain(PARA_ID), 1_000u128).into();
+		let expected = Xcm(vec![
+			WithdrawAsset(fee(500)),
+			BuyExecution {
+				fees: (Here, 500u128).into(),
+				weight_limit: Limited(BaseXcmWeight::get().saturating_mul(8)),
+			},
+			SetAppendix(Xcm(vec![ReportError(QueryResponseInfo {
+				destination: Parachain(PARA_ID).into(),
+				query_id: 0,
+				max_weight: <Test as crate::Config>::WeightInfo::notify_teleport(),
+			})])),
+			ReceiveTeleportedAsset(teleported.clone()),
+			DepositAsset { assets: Definite(teleported), beneficiary: beneficiary() },
+			RefundSurplus,
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: beneficiary() },
+		]);
+		let sent = sent_xcm();
+		assert_eq!(sent, vec![(dest(), expected)]);
+		assert_eq!(
+			sent[0].1.encode(),
+			hex!(
+				"1c"
+				// WithdrawAsset
+				"000400010000d107"
+				// BuyExecution
+				"1300000000d10701017d017d"
+				// SetAppendix(ReportError)
+				"16040c000100411f000220a1071949"
+				// ReceiveTeleportedAsset
+				"020400000100411f00a10f"
+				// DepositAsset of the teleported assets
+				"0d000400000100411f00a10f00010100"
+				"0202020202020202020202020202020202020202020202020202020202020202"
+				// RefundSurplus
+				"14"
+				// DepositAsset of the surplus
+				"0d01020400010100"
+				"0202020202020202020202020202020202020202020202020202020202020202"
+			)
+		);
+	});
+}
+
+#[test]
+fn sent_message_passes_the_destination_barrier() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+
+		let (_, mut message) = sent_xcm().remove(0);
+		let weight = BaseXcmWeight::get().saturating_mul(message.len() as u64);
+		assert_ok!(AllowTopLevelPaidExecutionFrom::<Everything>::should_execute(
+			&Parachain(PARA_ID).into(),
+			&mut message.0,
+			weight,
+			&mut Properties { weight_credit: Weight::zero(), message_id: None },
+		));
+	});
+}
+
+#[test]
+fn teleport_to_untrusted_destination_is_not_reported() {
+	new_test_ext().execute_with(|| {
+		let sibling = MultiLocation::new(1, X1(Parachain(1000)));
+		assert_ok!(WithdrawTeleport::limited_withdraw_and_teleport(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(sibling.into()),
+			Box::new(beneficiary().into()),
+			Box::new(native(1_000).into()),
+			Box::new(fee(500).into()),
+			0,
+			Unlimited,
+		));
+
+		assert!(PendingTeleports::<Test>::get(0).is_none());
+		let (_, message) = sent_xcm().remove(0);
+		assert!(!message.0.iter().any(|instruction| matches!(instruction, SetAppendix(_))));
+	});
+}
+
+#[test]
+fn teleport_filter_applies_to_each_asset() {
+	new_test_ext().execute_with(|| {
+		let other = MultiLocation::new(0, X1(GeneralIndex(1)));
+		NonTeleportableAssets::set(vec![other]);
+
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+		let assets: MultiAssets =
+			vec![MultiAsset::from((Here, 1_000u128)), (other, 1_000u128).into()].into();
+		assert_noop!(teleport(dest(), assets, fee(500)), pallet_xcm::Error::<Test>::Filtered);
+	});
+}
+
+#[test]
+fn teleport_fails_if_assets_cannot_be_reanchored() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			teleport(MultiLocation::new(3, Here), native(1_000), fee(500)),
+			pallet_xcm::Error::<Test>::CannotReanchor
+		);
+	});
+}
+
+#[test]
+fn nested_instructions_are_weighed_on_destination() {
+	new_test_ext().execute_with(|| {
+		// the report of a trusted destination nests an instruction in `SetAppendix`
+		let message = Xcm(vec![
+			ClearOrigin,
+			SetAppendix(Xcm(vec![ClearOrigin, SetErrorHandler(Xcm(vec![ClearOrigin]))])),
+		]);
+		assert_eq!(
+			<Test as crate::Config>::DestinationWeigher::weigh(&dest(), &message),
+			Some(BaseXcmWeight::get().saturating_mul(5))
+		);
+	});
+}
+
+#[test]
+fn weight_limit_must_cover_the_estimate() {
+	new_test_ext().execute_with(|| {
+		let limited_teleport = |weight_limit| {
+			WithdrawTeleport::limited_withdraw_and_teleport(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(dest().into()),
+				Box::new(beneficiary().into()),
+				Box::new(native(1_000).into()),
+				Box::new(fee(500).into()),
+				0,
+				weight_limit,
+			)
+		};
+		let estimate = BaseXcmWeight::get().saturating_mul(8);
+
+		assert_noop!(
+			limited_teleport(Limited(estimate.saturating_sub(Weight::from_parts(1, 0)))),
+			crate::Error::<Test>::WeightLimitTooLow
+		);
+		assert_ok!(limited_teleport(Limited(estimate)));
+	});
+}
+
+#[test]
+fn destinations_without_a_unit_weight_need_a_weight_limit() {
+	new_test_ext().execute_with(|| {
+		let sibling = MultiLocation::new(1, X1(Parachain(1000)));
+
+		assert_noop!(
+			teleport(sibling, native(1_000), fee(500)),
+			crate::Error::<Test>::CannotWeighOnDestination
+		);
+	});
+}
+
+#[test]
+fn several_assets_are_teleported_at_once() {
+	new_test_ext().execute_with(|| {
+		let assets: MultiAssets =
+			vec![MultiAsset::from((Here, 1_000u128)), (Parent, 200u128).into()].into();
+		assert_ok!(teleport(dest(), assets, fee(500)));
+
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 1_000);
+		assert_eq!(Assets::balance(FEE_ASSET_ID, ALICE), INITIAL_BALANCE - 200 - 500);
+		assert_eq!(PendingTeleports::<Test>::get(0).unwrap().assets.len(), 2);
+
+		let teleported: MultiAssets =
+			vec![MultiAsset::from((Here, 200u128)), (Parachain(PARA_ID), 1_000u128).into()].into();
+		let (_, message) = sent_xcm().remove(0);
+		assert!(message.0.contains(&ReceiveTeleportedAsset(teleported)));
+	});
+}
+
+#[test]
+fn fee_asset_item_picks_the_fee_asset() {
+	new_test_ext().execute_with(|| {
+		let teleport_paying_with = |fee_asset_item| {
+			WithdrawTeleport::withdraw_and_teleport(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(dest().into()),
+				Box::new(beneficiary().into()),
+				Box::new(native(1_000).into()),
+				// sorted, the native asset first
+				Box::new(
+					MultiAssets::from(vec![(Parent, 500u128).into(), (Here, 300u128).into()])
+						.into(),
+				),
+				fee_asset_item,
+			)
+		};
+
+		assert_noop!(teleport_paying_with(2), crate::Error::<Test>::FeeAssetNotFound);
+		assert_ok!(teleport_paying_with(1));
+
+		let (_, message) = sent_xcm().remove(0);
+		assert!(matches!(
+			&message.0[1],
+			BuyExecution { fees, .. } if *fees == (Here, 500u128).into()
+		));
+		// what is left of both fee assets is deposited
+		assert_eq!(
+			message.0.last(),
+			Some(&DepositAsset { assets: Wild(AllCounted(2)), beneficiary: beneficiary() })
+		);
+	});
+}
+
+#[test]
+fn teleport_fails_with_an_empty_or_oversized_fee_list() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			teleport(dest(), native(1_000), MultiAssets::new()),
+			pallet_xcm::Error::<Test>::Empty
+		);
+
+		let fee_assets: Vec<MultiAsset> = (0..9)
+			.map(|index| (MultiLocation::new(1, X1(GeneralIndex(index))), 1u128).into())
+			.collect();
+		assert_noop!(
+			teleport(dest(), native(1_000), fee_assets.into()),
+			pallet_xcm::Error::<Test>::TooManyAssets
+		);
+	});
+}
+
+#[test]
+fn failed_local_execution_has_no_side_effects() {
+	new_test_ext().execute_with(|| {
+		// the fee asset cannot be withdrawn once the native asset is burnt
+		assert_noop!(
+			teleport(dest(), native(1_000), fee(INITIAL_BALANCE + 1)),
+			crate::Error::<Test>::FailedToExecuteXcm
+		);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn failed_teleport_is_refunded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+
+		assert_ok!(respond(dest(), Some((3, XcmError::UntrustedTeleportLocation))));
+
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert!(PendingTeleports::<Test>::get(0).is_none());
+		System::assert_last_event(
+			Event::<Test>::TeleportFailed {
+				query_id: 0,
+				origin: alice(),
+				assets: native(1_000),
+				error: XcmError::UntrustedTeleportLocation,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn failed_deposit_is_not_refunded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+
+		// some of the teleported assets may have been deposited on destination
+		assert_ok!(respond(dest(), Some((4, XcmError::FailedToTransactAsset("")))));
+
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 1_000);
+		assert!(PendingTeleports::<Test>::get(0).is_none());
+		System::assert_last_event(
+			Event::<Test>::TeleportIncomplete {
+				query_id: 0,
+				error: XcmError::FailedToTransactAsset(""),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn unreported_teleport_is_refunded_once_expired() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+
+		System::set_block_number(1 + ResponseTimeout::get());
+		assert_noop!(
+			WithdrawTeleport::expire_teleport(RuntimeOrigin::root(), 0),
+			crate::Error::<Test>::TeleportNotExpired
+		);
+
+		System::set_block_number(2 + ResponseTimeout::get());
+		assert_ok!(WithdrawTeleport::expire_teleport(RuntimeOrigin::root(), 0));
+
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert!(PendingTeleports::<Test>::get(0).is_none());
+		System::assert_last_event(
+			Event::<Test>::TeleportExpired { query_id: 0, origin: alice(), assets: native(1_000) }
+				.into(),
+		);
+		assert_noop!(
+			WithdrawTeleport::expire_teleport(RuntimeOrigin::root(), 0),
+			crate::Error::<Test>::UnknownTeleport
+		);
+	});
+}
+
+#[test]
+fn only_expire_origin_refunds_unreported_teleports() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+
+		// the message may only be delayed on destination, refunding it could duplicate the assets
+		System::set_block_number(2 + ResponseTimeout::get());
+		assert_noop!(
+			WithdrawTeleport::expire_teleport(RuntimeOrigin::signed(ALICE), 0),
+			DispatchError::BadOrigin
+		);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 1_000);
+		assert!(PendingTeleports::<Test>::get(0).is_some());
+	});
+}
+
+#[test]
+fn teleport_completes_once_assets_are_deposited() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+
+		// depositing the fee assets left failed after the teleported assets were deposited
+		assert_ok!(respond(dest(), Some((6, XcmError::FailedToTransactAsset("")))));
+
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 1_000);
+		assert!(PendingTeleports::<Test>::get(0).is_none());
+		System::assert_last_event(Event::<Test>::TeleportCompleted { query_id: 0 }.into());
+	});
+}
+
+#[test]
+fn teleport_outcome_is_only_accepted_from_destination() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+
+		assert_noop!(
+			respond(MultiLocation::new(1, X1(Parachain(1000))), None),
+			DispatchError::BadOrigin
+		);
+		assert!(PendingTeleports::<Test>::get(0).is_some());
+	});
+}
+
+#[test]
+fn dry_run_has_no_side_effects() {
+	new_test_ext().execute_with(|| {
+		let effects = WithdrawTeleport::dry_run_withdraw_and_teleport(
+			alice().into(),
+			dest().into(),
+			beneficiary().into(),
+			native(1_000).into(),
+			fee(500).into(),
+			0,
+			None,
+		)
+		.unwrap();
+
+		assert!(matches!(effects.outcome, Outcome::Complete(_)));
+		assert_eq!(effects.destination_weight, Limited(BaseXcmWeight::get().saturating_mul(8)));
+		assert_eq!(effects.destination_fee, None);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert!(PendingTeleports::<Test>::get(0).is_none());
+		assert!(sent_xcm().is_empty());
+
+		// the message dry-run is the one sent
+		assert_ok!(teleport(dest(), native(1_000), fee(500)));
+		assert_eq!(VersionedXcm::from(sent_xcm()[0].1.clone()), effects.message);
+	});
+}
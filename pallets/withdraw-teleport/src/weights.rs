@@ -31,6 +31,8 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_withdraw_teleport.
 pub trait WeightInfo {
 	fn withdraw_and_teleport() -> Weight;
+	fn notify_teleport() -> Weight;
+	fn expire_teleport() -> Weight;
 }
 
 /// Weights for pallet_withdraw_teleport using the Substrate node and recommended hardware.
@@ -48,13 +50,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
 	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:0 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
 	fn withdraw_and_teleport() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `177`
 		//  Estimated: `3642`
-		// Minimum execution time: 87_000_000 picoseconds.
-		Weight::from_parts(88_000_000, 3642)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+		// Minimum execution time: 97_000_000 picoseconds.
+		Weight::from_parts(98_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:1 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn notify_teleport() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `4678`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 4678)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:1 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn expire_teleport() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `4678`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4678)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
@@ -73,13 +107,45 @@ impl WeightInfo for () {
 	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
 	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:0 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
 	fn withdraw_and_teleport() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `177`
 		//  Estimated: `3642`
-		// Minimum execution time: 87_000_000 picoseconds.
-		Weight::from_parts(88_000_000, 3642)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+		// Minimum execution time: 97_000_000 picoseconds.
+		Weight::from_parts(98_000_000, 3642)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:1 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn notify_teleport() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `4678`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 4678)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:1 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn expire_teleport() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `4678`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4678)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
@@ -0,0 +1,58 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarking setup for pallet-xcm-pause
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as XcmPause;
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+
+#[benchmarks(where T::MessageOrigin: From<u32>)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn pause_origin() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let message_origin = T::MessageOrigin::from(1000);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, message_origin.clone());
+
+		assert!(PausedOrigins::<T>::contains_key(message_origin));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resume_origin() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let message_origin = T::MessageOrigin::from(1000);
+		PausedOrigins::<T>::insert(&message_origin, ());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, message_origin.clone());
+
+		assert!(!PausedOrigins::<T>::contains_key(message_origin));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(XcmPause, crate::mock::new_test_ext(), crate::mock::Test);
+}
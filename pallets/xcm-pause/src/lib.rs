@@ -0,0 +1,151 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pauses the execution of inbound XCM, either from every origin while the chain is in safe mode
+//! or from the origins paused by governance.
+//!
+//! The pallet is queried by the message queue before servicing a queue, so messages received in
+//! the meantime are kept and executed once their origin is resumed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::QueuePausedQuery};
+	use frame_system::pallet_prelude::*;
+	use xcm_primitives::PauseXcmExecution;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin of the inbound message queues, e.g. `AggregateMessageOrigin`.
+		type MessageOrigin: Parameter + MaxEncodedLen;
+
+		/// Origin pausing and resuming message queues.
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Whether the execution of inbound XCM is suspended for every origin.
+	#[pallet::storage]
+	pub type XcmExecutionSuspended<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Origins whose inbound XCM is paused.
+	#[pallet::storage]
+	pub type PausedOrigins<T: Config> = StorageMap<_, Blake2_128Concat, T::MessageOrigin, ()>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Inbound XCM is no longer executed, from any origin.
+		ExecutionSuspended,
+		/// Inbound XCM is executed again, except from paused origins.
+		ExecutionResumed,
+		/// Inbound XCM from `origin` is no longer executed.
+		OriginPaused { origin: T::MessageOrigin },
+		/// Inbound XCM from `origin` is executed again.
+		OriginResumed { origin: T::MessageOrigin },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The execution of inbound XCM is already suspended
+		AlreadySuspended,
+		/// The execution of inbound XCM is not suspended
+		NotSuspended,
+		/// Inbound XCM from the origin is already paused
+		AlreadyPaused,
+		/// Inbound XCM from the origin is not paused
+		NotPaused,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Stop executing the XCM received from `message_origin`, which is kept queued until the
+		/// origin is resumed.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::pause_origin())]
+		pub fn pause_origin(
+			origin: OriginFor<T>,
+			message_origin: T::MessageOrigin,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(!PausedOrigins::<T>::contains_key(&message_origin), Error::<T>::AlreadyPaused);
+
+			PausedOrigins::<T>::insert(&message_origin, ());
+			Self::deposit_event(Event::OriginPaused { origin: message_origin });
+			Ok(())
+		}
+
+		/// Resume executing the XCM received from `message_origin`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::resume_origin())]
+		pub fn resume_origin(
+			origin: OriginFor<T>,
+			message_origin: T::MessageOrigin,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(PausedOrigins::<T>::contains_key(&message_origin), Error::<T>::NotPaused);
+
+			PausedOrigins::<T>::remove(&message_origin);
+			Self::deposit_event(Event::OriginResumed { origin: message_origin });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> PauseXcmExecution for Pallet<T> {
+		fn suspend_xcm_execution() -> DispatchResult {
+			ensure!(!XcmExecutionSuspended::<T>::get(), Error::<T>::AlreadySuspended);
+
+			XcmExecutionSuspended::<T>::put(true);
+			Self::deposit_event(Event::ExecutionSuspended);
+			Ok(())
+		}
+
+		fn resume_xcm_execution() -> DispatchResult {
+			ensure!(XcmExecutionSuspended::<T>::get(), Error::<T>::NotSuspended);
+
+			XcmExecutionSuspended::<T>::kill();
+			Self::deposit_event(Event::ExecutionResumed);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> QueuePausedQuery<T::MessageOrigin> for Pallet<T> {
+		fn is_paused(origin: &T::MessageOrigin) -> bool {
+			XcmExecutionSuspended::<T>::get() || PausedOrigins::<T>::contains_key(origin)
+		}
+	}
+}
@@ -0,0 +1,146 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{EnqueueMessage, QueuePausedQuery, ServiceQueues},
+	weights::Weight,
+	BoundedSlice,
+};
+use sp_runtime::DispatchError;
+use xcm_primitives::PauseXcmExecution;
+
+use crate::{mock::*, Error, Event, PausedOrigins};
+
+const SIBLING: u32 = 2000;
+
+// Queues a message from `origin` in the message queue.
+fn enqueue(origin: u32) {
+	MessageQueue::enqueue_message(BoundedSlice::defensive_truncate_from(b"message"), origin);
+}
+
+// Services the message queue, returning the origins of the messages processed.
+fn service_queues() -> Vec<u32> {
+	System::reset_events();
+	MessageQueue::service_queues(Weight::MAX);
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+				origin, ..
+			}) => Some(origin),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn origins_are_paused_and_resumed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XcmPause::pause_origin(RuntimeOrigin::root(), SIBLING));
+		assert!(PausedOrigins::<Test>::contains_key(SIBLING));
+		assert!(XcmPause::is_paused(&SIBLING));
+		assert!(!XcmPause::is_paused(&1000));
+		System::assert_last_event(Event::<Test>::OriginPaused { origin: SIBLING }.into());
+
+		assert_ok!(XcmPause::resume_origin(RuntimeOrigin::root(), SIBLING));
+		assert!(!XcmPause::is_paused(&SIBLING));
+		System::assert_last_event(Event::<Test>::OriginResumed { origin: SIBLING }.into());
+	});
+}
+
+#[test]
+fn pausing_origins_requires_pause_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmPause::pause_origin(RuntimeOrigin::signed(1), SIBLING),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmPause::resume_origin(RuntimeOrigin::signed(1), SIBLING),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn origins_are_paused_once() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmPause::resume_origin(RuntimeOrigin::root(), SIBLING),
+			Error::<Test>::NotPaused
+		);
+
+		assert_ok!(XcmPause::pause_origin(RuntimeOrigin::root(), SIBLING));
+		assert_noop!(
+			XcmPause::pause_origin(RuntimeOrigin::root(), SIBLING),
+			Error::<Test>::AlreadyPaused
+		);
+	});
+}
+
+#[test]
+fn suspending_execution_pauses_every_origin() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XcmPause::pause_origin(RuntimeOrigin::root(), SIBLING));
+
+		assert_ok!(XcmPause::suspend_xcm_execution());
+		assert!(XcmPause::is_paused(&0));
+		assert!(XcmPause::is_paused(&SIBLING));
+		System::assert_last_event(Event::<Test>::ExecutionSuspended.into());
+		assert_noop!(XcmPause::suspend_xcm_execution(), Error::<Test>::AlreadySuspended);
+
+		// origins paused by governance stay paused
+		assert_ok!(XcmPause::resume_xcm_execution());
+		assert!(!XcmPause::is_paused(&0));
+		assert!(XcmPause::is_paused(&SIBLING));
+		System::assert_last_event(Event::<Test>::ExecutionResumed.into());
+		assert_noop!(XcmPause::resume_xcm_execution(), Error::<Test>::NotSuspended);
+	});
+}
+
+#[test]
+fn messages_of_paused_origins_are_queued_until_resumed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XcmPause::pause_origin(RuntimeOrigin::root(), SIBLING));
+
+		enqueue(SIBLING);
+		enqueue(1000);
+		assert_eq!(service_queues(), vec![1000]);
+		assert!(service_queues().is_empty());
+
+		// the message kept in the queue is executed once the origin is resumed
+		assert_ok!(XcmPause::resume_origin(RuntimeOrigin::root(), SIBLING));
+		assert_eq!(service_queues(), vec![SIBLING]);
+	});
+}
+
+#[test]
+fn messages_are_queued_while_execution_is_suspended() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XcmPause::suspend_xcm_execution());
+
+		enqueue(SIBLING);
+		enqueue(1000);
+		assert!(service_queues().is_empty());
+
+		assert_ok!(XcmPause::resume_xcm_execution());
+		let mut drained = service_queues();
+		drained.sort();
+		assert_eq!(drained, vec![1000, SIBLING]);
+	});
+}
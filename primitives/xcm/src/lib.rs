@@ -17,59 +17,419 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod tests;
+
 use frame_support::{
 	sp_runtime::SaturatedConversion,
-	traits::{fungibles::Inspect, Currency},
-	weights::Weight,
+	traits::{fungibles::Inspect, nonfungibles, ContainsPair, Currency, Get},
+	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_runtime::traits::MaybeEquivalence;
 #[cfg(not(test))]
 use sp_runtime::DispatchResult;
 use sp_std::marker::PhantomData;
 use xcm::{
 	latest::{
-		AssetId::Concrete, Fungibility::Fungible, Junctions::Here, MultiAsset, MultiLocation,
+		AssetId::{self as XcmAssetId, Concrete},
+		AssetInstance,
+		Fungibility::{Fungible, NonFungible},
+		Junction::{GlobalConsensus, Parachain},
+		Junctions::Here,
+		MultiAsset, MultiLocation,
 	},
-	v3::XcmContext,
+	v3::{Error as XcmError, Result as XcmResult, XcmContext},
 };
 use xcm_executor::{
-	traits::{DropAssets, Error as MatchError, MatchesFungibles},
+	traits::{
+		DropAssets, Error as MatchError, MatchesFungibles, MatchesNonFungibles, TakeRevenue,
+		TransactAsset, WeightTrader,
+	},
 	Assets,
 };
 
 pub struct AsAssetMultiLocation<AssetId, AssetIdInfoGetter>(
 	PhantomData<(AssetId, AssetIdInfoGetter)>,
 );
-impl<AssetId, AssetIdInfoGetter> MaybeEquivalence<MultiLocation, AssetId>
+impl<AssetId, Location, AssetIdInfoGetter> MaybeEquivalence<Location, AssetId>
 	for AsAssetMultiLocation<AssetId, AssetIdInfoGetter>
 where
 	AssetId: Clone,
-	AssetIdInfoGetter: AssetMultiLocationGetter<AssetId>,
+	AssetIdInfoGetter: AssetMultiLocationGetter<AssetId, Location>,
 {
-	fn convert(asset_multi_location: &MultiLocation) -> Option<AssetId> {
+	fn convert(asset_multi_location: &Location) -> Option<AssetId> {
 		AssetIdInfoGetter::get_asset_id(asset_multi_location)
 	}
 
-	fn convert_back(asset_id: &AssetId) -> Option<MultiLocation> {
+	fn convert_back(asset_id: &AssetId) -> Option<Location> {
 		AssetIdInfoGetter::get_asset_multi_location(asset_id.clone())
 	}
 }
 
-pub trait AssetMultiLocationGetter<AssetId> {
-	fn get_asset_multi_location(asset_id: AssetId) -> Option<MultiLocation>;
-	fn get_asset_id(asset_multi_location: &MultiLocation) -> Option<AssetId>;
+/// Location of the chain holding the reserve of the asset at `asset_location`.
+///
+/// This is the longest prefix of the location made of consensus junctions only, e.g.
+/// `(1, Parachain(1000))` for an asset living in a pallet of Asset Hub.
+pub fn reserve_chain(asset_location: &MultiLocation) -> MultiLocation {
+	let mut chain = MultiLocation { parents: asset_location.parents, interior: Here };
+	for junction in asset_location.interior.iter() {
+		match junction {
+			GlobalConsensus(_) | Parachain(_) => {
+				if chain.push_interior(*junction).is_err() {
+					break;
+				}
+			},
+			_ => break,
+		}
+	}
+	chain
 }
 
-pub struct ConvertedRegisteredAssetId<AssetId, Balance, ConvertAssetId, ConvertBalance>(
-	PhantomData<(AssetId, Balance, ConvertAssetId, ConvertBalance)>,
-);
+/// Resolves registered assets to their location and back.
+///
+/// `Location` defaults to the location type of the latest XCM version, but can be set to the
+/// location type of another version, e.g. while storage is being migrated between versions.
+pub trait AssetMultiLocationGetter<AssetId, Location = MultiLocation> {
+	fn get_asset_multi_location(asset_id: AssetId) -> Option<Location>;
+	fn get_asset_id(asset_multi_location: &Location) -> Option<AssetId>;
+}
+
+/// How far a registered asset is trusted when it arrives from another chain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum AssetTrust {
+	/// Reserve transfers from the chain holding the asset's reserve are accepted.
+	#[default]
+	Reserve,
+	/// Teleports from the chain holding the asset's reserve are accepted.
+	Teleport,
+	/// The asset is bridged from another consensus; reserve transfers are accepted from the
+	/// given location only, e.g. the bridge hub or Asset Hub.
+	Bridged(MultiLocation),
+}
+
+pub trait AssetTrustGetter {
+	fn get_asset_trust(asset_multi_location: &MultiLocation) -> Option<AssetTrust>;
+}
+
+/// `IsReserve` accepting registered assets whose trust is `Reserve` or `Bridged`, when they come
+/// from their trusted reserve.
+pub struct RegisteredReserves<TrustGetter>(PhantomData<TrustGetter>);
+impl<TrustGetter: AssetTrustGetter> ContainsPair<MultiAsset, MultiLocation>
+	for RegisteredReserves<TrustGetter>
+{
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		let Concrete(asset_location) = &asset.id else { return false };
+		match TrustGetter::get_asset_trust(asset_location) {
+			Some(AssetTrust::Reserve) => &reserve_chain(asset_location) == origin,
+			Some(AssetTrust::Bridged(reserve)) => &reserve == origin,
+			Some(AssetTrust::Teleport) | None => false,
+		}
+	}
+}
+
+/// `IsTeleporter` accepting registered assets whose trust is `Teleport`, when they come from
+/// the chain holding their reserve.
+pub struct RegisteredTeleporters<TrustGetter>(PhantomData<TrustGetter>);
+impl<TrustGetter: AssetTrustGetter> ContainsPair<MultiAsset, MultiLocation>
+	for RegisteredTeleporters<TrustGetter>
+{
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		let Concrete(asset_location) = &asset.id else { return false };
+		TrustGetter::get_asset_trust(asset_location) == Some(AssetTrust::Teleport)
+			&& &reserve_chain(asset_location) == origin
+	}
+}
+
+pub trait UnitsPerSecondGetter {
+	fn get_units_per_second(asset_multi_location: &MultiLocation) -> Option<u128>;
+}
+
+/// Charges execution in the first fungible asset of the payment that has a rate in
+/// `RateGetter`, e.g. a registered asset whose units per second were set by governance.
+///
+/// Once weight was bought with an asset, later purchases of the same message must use that asset
+/// too. Fees of the bought weight which is not refunded are handed to `R`.
+///
+/// Only the `ref_time` of the weight is charged, unlike `FixedRateOfFungible` which also charges
+/// per MB of proof size: the registry stores no rate for proof size, so it is free, as it is for
+/// the fixed rates of the runtime which set none per MB either.
+pub struct UsingRegisteredUnitsPerSecond<RateGetter: UnitsPerSecondGetter, R: TakeRevenue> {
+	weight: Weight,
+	consumed: u128,
+	asset_location_and_units_per_second: Option<(MultiLocation, u128)>,
+	_phantom: PhantomData<(RateGetter, R)>,
+}
+
+impl<RateGetter: UnitsPerSecondGetter, R: TakeRevenue>
+	UsingRegisteredUnitsPerSecond<RateGetter, R>
+{
+	// The proof size of `weight` is not charged.
+	fn amount(weight: Weight, units_per_second: u128) -> u128 {
+		units_per_second.saturating_mul(weight.ref_time().into())
+			/ u128::from(WEIGHT_REF_TIME_PER_SECOND)
+	}
+}
+
+impl<RateGetter: UnitsPerSecondGetter, R: TakeRevenue> WeightTrader
+	for UsingRegisteredUnitsPerSecond<RateGetter, R>
+{
+	fn new() -> Self {
+		Self {
+			weight: Weight::zero(),
+			consumed: 0,
+			asset_location_and_units_per_second: None,
+			_phantom: PhantomData,
+		}
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: Weight,
+		payment: Assets,
+		_context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		let (asset_location, units_per_second) = match self.asset_location_and_units_per_second {
+			Some(bought_with) => bought_with,
+			None => payment
+				.fungible
+				.keys()
+				.find_map(|id| match id {
+					Concrete(location) => {
+						RateGetter::get_units_per_second(location).map(|rate| (*location, rate))
+					},
+					_ => None,
+				})
+				.ok_or(XcmError::AssetNotFound)?,
+		};
+
+		let amount = Self::amount(weight, units_per_second);
+		if amount == 0 {
+			return Ok(payment);
+		}
+		let unused = payment
+			.checked_sub((asset_location, amount).into())
+			.map_err(|_| XcmError::TooExpensive)?;
+
+		self.weight = self.weight.saturating_add(weight);
+		self.consumed = self.consumed.saturating_add(amount);
+		self.asset_location_and_units_per_second = Some((asset_location, units_per_second));
+		Ok(unused)
+	}
+
+	fn refund_weight(&mut self, weight: Weight, _context: &XcmContext) -> Option<MultiAsset> {
+		let (asset_location, units_per_second) = self.asset_location_and_units_per_second?;
+		let weight = weight.min(self.weight);
+		let amount = Self::amount(weight, units_per_second).min(self.consumed);
+		self.weight = self.weight.saturating_sub(weight);
+		self.consumed = self.consumed.saturating_sub(amount);
+		(amount > 0).then(|| (asset_location, amount).into())
+	}
+}
+
+impl<RateGetter: UnitsPerSecondGetter, R: TakeRevenue> Drop
+	for UsingRegisteredUnitsPerSecond<RateGetter, R>
+{
+	fn drop(&mut self) {
+		if let Some((asset_location, _)) = self.asset_location_and_units_per_second {
+			if self.consumed > 0 {
+				R::take_revenue((asset_location, self.consumed).into());
+			}
+		}
+	}
+}
+
+/// Decimals of a derivative asset and of the original asset on its reserve chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetDecimals {
+	pub local: u8,
+	pub reserve: u8,
+}
+
+impl AssetDecimals {
+	/// Converts an amount expressed in reserve units into local units.
+	///
+	/// Returns `None` on overflow or if the conversion would lose precision.
+	pub fn to_local(&self, amount: u128) -> Option<u128> {
+		Self::scale(amount, self.reserve, self.local)
+	}
+
+	/// Converts an amount expressed in local units into reserve units.
+	///
+	/// Returns `None` on overflow or if the conversion would lose precision.
+	pub fn to_reserve(&self, amount: u128) -> Option<u128> {
+		Self::scale(amount, self.local, self.reserve)
+	}
+
+	fn scale(amount: u128, from: u8, to: u8) -> Option<u128> {
+		if to >= from {
+			10u128.checked_pow((to - from).into())?.checked_mul(amount)
+		} else {
+			let factor = 10u128.checked_pow((from - to).into())?;
+			(amount % factor == 0).then(|| amount / factor)
+		}
+	}
+}
+
+pub trait AssetDecimalsGetter<AssetId> {
+	fn get_asset_decimals(asset_id: &AssetId) -> Option<AssetDecimals>;
+}
+
+impl<AssetId> AssetDecimalsGetter<AssetId> for () {
+	fn get_asset_decimals(_asset_id: &AssetId) -> Option<AssetDecimals> {
+		None
+	}
+}
+
+/// Whether a registered asset may currently be transferred over XCM.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum AssetStatus {
+	#[default]
+	Active,
+	/// Frozen by governance, e.g. because its reserve chain was compromised.
+	Frozen,
+	/// Put aside after exceeding one of its flow limits, until governance releases it.
+	Quarantined,
+	/// Being unregistered: holders may still withdraw it, but no more of it may arrive.
+	Deprecated,
+}
+
+impl AssetStatus {
+	pub fn is_active(&self) -> bool {
+		matches!(self, AssetStatus::Active)
+	}
+
+	/// Whether the asset may still leave the chain.
+	pub fn is_withdrawable(&self) -> bool {
+		matches!(self, AssetStatus::Active | AssetStatus::Deprecated)
+	}
+}
+
+pub trait AssetStatusGetter<AssetId> {
+	fn get_asset_status(asset_id: &AssetId) -> AssetStatus;
+}
+
+impl<AssetId> AssetStatusGetter<AssetId> for () {
+	fn get_asset_status(_asset_id: &AssetId) -> AssetStatus {
+		AssetStatus::Active
+	}
+}
+
+/// Accounts for amounts of assets entering and leaving the chain over XCM.
+pub trait AssetFlowLimiter {
+	/// Notes `amount` of the asset at `asset_location` arriving, failing when it would exceed the
+	/// asset's inbound limit or the asset no longer accepts deposits.
+	fn note_inbound(asset_location: &MultiLocation, amount: u128) -> Result<(), XcmError>;
+	/// Notes `amount` of the asset at `asset_location` leaving, failing when it would exceed the
+	/// asset's outbound limit.
+	fn note_outbound(asset_location: &MultiLocation, amount: u128) -> Result<(), XcmError>;
+}
+
+impl AssetFlowLimiter for () {
+	fn note_inbound(_asset_location: &MultiLocation, _amount: u128) -> Result<(), XcmError> {
+		Ok(())
+	}
+	fn note_outbound(_asset_location: &MultiLocation, _amount: u128) -> Result<(), XcmError> {
+		Ok(())
+	}
+}
+
+/// Wraps an asset transactor so that deposits and withdrawals of fungible assets are checked
+/// against `Limiter` first.
+pub struct FlowLimitedTransactor<Transactor, Limiter>(PhantomData<(Transactor, Limiter)>);
+impl<Transactor: TransactAsset, Limiter: AssetFlowLimiter>
+	FlowLimitedTransactor<Transactor, Limiter>
+{
+	fn fungible(what: &MultiAsset) -> Option<(&MultiLocation, u128)> {
+		match (&what.id, &what.fun) {
+			(Concrete(location), Fungible(amount)) => Some((location, *amount)),
+			_ => None,
+		}
+	}
+}
+
+impl<Transactor: TransactAsset, Limiter: AssetFlowLimiter> TransactAsset
+	for FlowLimitedTransactor<Transactor, Limiter>
+{
+	fn can_check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Transactor::can_check_in(origin, what, context)
+	}
+
+	fn check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Transactor::check_in(origin, what, context)
+	}
+
+	fn can_check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Transactor::can_check_out(dest, what, context)
+	}
+
+	fn check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Transactor::check_out(dest, what, context)
+	}
+
+	fn deposit_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		context: Option<&XcmContext>,
+	) -> XcmResult {
+		if let Some((location, amount)) = Self::fungible(what) {
+			Limiter::note_inbound(location, amount)?;
+		}
+		Transactor::deposit_asset(what, who, context)
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		if let Some((location, amount)) = Self::fungible(what) {
+			Limiter::note_outbound(location, amount)?;
+		}
+		Transactor::withdraw_asset(what, who, maybe_context)
+	}
+
+	fn internal_transfer_asset(
+		what: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		Transactor::internal_transfer_asset(what, from, to, context)
+	}
+}
+
+/// Matches registered fungible assets.
+///
+/// Incoming amounts are expressed in the reserve's units and get scaled to the derivative's
+/// decimals whenever `DecimalsGetter` knows about a mismatch. Assets which `StatusGetter` reports
+/// as frozen or quarantined are not matched. Deprecated assets are still matched so holders can
+/// withdraw them, deposits of those are refused by the `AssetFlowLimiter` of the registry.
+pub struct ConvertedRegisteredAssetId<
+	AssetId,
+	Balance,
+	ConvertAssetId,
+	ConvertBalance,
+	DecimalsGetter = (),
+	StatusGetter = (),
+>(PhantomData<(AssetId, Balance, ConvertAssetId, ConvertBalance, DecimalsGetter, StatusGetter)>);
 impl<
 		AssetId: Clone,
 		Balance: Clone,
 		ConvertAssetId: MaybeEquivalence<MultiLocation, AssetId>,
 		ConvertBalance: MaybeEquivalence<Balance, u128>,
+		DecimalsGetter: AssetDecimalsGetter<AssetId>,
+		StatusGetter: AssetStatusGetter<AssetId>,
 	> MatchesFungibles<AssetId, Balance>
-	for ConvertedRegisteredAssetId<AssetId, Balance, ConvertAssetId, ConvertBalance>
+	for ConvertedRegisteredAssetId<
+		AssetId,
+		Balance,
+		ConvertAssetId,
+		ConvertBalance,
+		DecimalsGetter,
+		StatusGetter,
+	>
 {
 	fn matches_fungibles(a: &MultiAsset) -> Result<(AssetId, Balance), MatchError> {
 		let (amount, id) = match (&a.fun, &a.id) {
@@ -77,91 +437,258 @@ impl<
 			_ => return Err(MatchError::AssetNotHandled),
 		};
 		let what = ConvertAssetId::convert(id).ok_or(MatchError::AssetNotHandled)?;
-		let amount = ConvertBalance::convert_back(amount)
+		if !StatusGetter::get_asset_status(&what).is_withdrawable() {
+			return Err(MatchError::AssetNotHandled);
+		}
+		let amount = match DecimalsGetter::get_asset_decimals(&what) {
+			Some(decimals) => {
+				decimals.to_local(*amount).ok_or(MatchError::AmountToBalanceConversionFailed)?
+			},
+			None => *amount,
+		};
+		let amount = ConvertBalance::convert_back(&amount)
 			.ok_or(MatchError::AmountToBalanceConversionFailed)?;
 		Ok((what, amount))
 	}
 }
 
+/// Resolves registered non-fungible collections to their location and back.
+pub trait CollectionMultiLocationGetter<CollectionId> {
+	fn get_collection_multi_location(collection_id: CollectionId) -> Option<MultiLocation>;
+	fn get_collection_id(collection_multi_location: &MultiLocation) -> Option<CollectionId>;
+}
+
+/// Matches items of registered non-fungible collections.
+///
+/// The collection is resolved through `CollectionGetter`, the instance is converted to a local
+/// item id with `ConvertItemId`, e.g. `JustTry`.
+pub struct ConvertedRegisteredCollectionId<CollectionId, ItemId, CollectionGetter, ConvertItemId>(
+	PhantomData<(CollectionId, ItemId, CollectionGetter, ConvertItemId)>,
+);
+impl<
+		CollectionId,
+		ItemId,
+		CollectionGetter: CollectionMultiLocationGetter<CollectionId>,
+		ConvertItemId: MaybeEquivalence<AssetInstance, ItemId>,
+	> MatchesNonFungibles<CollectionId, ItemId>
+	for ConvertedRegisteredCollectionId<CollectionId, ItemId, CollectionGetter, ConvertItemId>
+{
+	fn matches_nonfungibles(a: &MultiAsset) -> Result<(CollectionId, ItemId), MatchError> {
+		let (instance, location) = match (&a.fun, &a.id) {
+			(NonFungible(ref instance), Concrete(ref location)) => (instance, location),
+			_ => return Err(MatchError::AssetNotHandled),
+		};
+		let collection_id =
+			CollectionGetter::get_collection_id(location).ok_or(MatchError::AssetNotHandled)?;
+		let item_id =
+			ConvertItemId::convert(instance).ok_or(MatchError::InstanceConversionFailed)?;
+		Ok((collection_id, item_id))
+	}
+}
+
 pub trait DropAssetsWeigher {
 	fn fungible() -> Weight;
 	fn native() -> Weight;
+	fn non_fungible() -> Weight;
 	fn default() -> Weight;
 }
 
+/// Records amounts of assets which are lost for good.
+pub trait RecordLostAsset {
+	fn record_lost_asset(origin: &MultiLocation, asset_id: &XcmAssetId, amount: u128);
+}
+
+impl RecordLostAsset for () {
+	fn record_lost_asset(_origin: &MultiLocation, _asset_id: &XcmAssetId, _amount: u128) {}
+}
+
+/// Handles the fungible amounts left in holding which `TrappistDropAssets` does not trap.
+pub trait HandleDust {
+	/// Handles the amounts of known assets below their minimum balance, left in holding by a
+	/// single message.
+	fn handle_dust(origin: &MultiLocation, dust: Assets, context: &XcmContext);
+	/// Handles an amount of an asset this chain does not know about.
+	fn handle_unknown(origin: &MultiLocation, asset_id: &XcmAssetId, amount: u128);
+}
+
+/// Drops the dust.
+impl HandleDust for () {
+	fn handle_dust(_origin: &MultiLocation, _dust: Assets, _context: &XcmContext) {}
+	fn handle_unknown(_origin: &MultiLocation, _asset_id: &XcmAssetId, _amount: u128) {}
+}
+
+/// Deposits dust into the account at `DustLocation`, e.g. the treasury, through `Transactor`.
+///
+/// The dust of a message is deposited in one batch, each asset at most once. `Transactor` should
+/// not apply flow limits: dust is not a transfer, and must not use up the allowance of an asset or
+/// quarantine it. Amounts of unknown assets, and dust which can't be deposited, are passed to
+/// `Recorder`.
+pub struct DepositDust<Transactor, DustLocation, Recorder>(
+	PhantomData<(Transactor, DustLocation, Recorder)>,
+);
+impl<Transactor, DustLocation, Recorder> HandleDust
+	for DepositDust<Transactor, DustLocation, Recorder>
+where
+	Transactor: TransactAsset,
+	DustLocation: Get<MultiLocation>,
+	Recorder: RecordLostAsset,
+{
+	fn handle_dust(origin: &MultiLocation, dust: Assets, context: &XcmContext) {
+		let dust_location = DustLocation::get();
+		for asset in dust.into_assets_iter() {
+			// the account may not exist yet for the asset, in which case the dust is lost
+			if Transactor::deposit_asset(&asset, &dust_location, Some(context)).is_err() {
+				if let Fungible(amount) = asset.fun {
+					Recorder::record_lost_asset(origin, &asset.id, amount);
+				}
+			}
+		}
+	}
+
+	fn handle_unknown(origin: &MultiLocation, asset_id: &XcmAssetId, amount: u128) {
+		Recorder::record_lost_asset(origin, asset_id, amount)
+	}
+}
+
+/// Traps the assets left in holding which can be claimed back, and drops the rest.
+///
+/// Non-fungible assets are trapped when `NonFungiblesMatcher` resolves them to an existing
+/// collection of `CollectionsPallet`, e.g. a local collection or a registered reserve collection.
+/// Fungible amounts below the minimum balance of a known asset, or of an unknown asset, are passed
+/// to `DustHandler`. Amounts in holding are expressed in the reserve's units, and are scaled with
+/// `DecimalsGetter` before being compared with the minimum balance of the derivative.
 pub struct TrappistDropAssets<
 	AssetId,
 	AssetIdInfoGetter,
 	AssetsPallet,
 	BalancesPallet,
+	NonFungiblesMatcher,
+	CollectionsPallet,
 	XcmPallet,
 	AccountId,
 	Weigher,
+	DustHandler,
+	DecimalsGetter = (),
 >(
 	PhantomData<(
 		AssetId,
 		AssetIdInfoGetter,
 		AssetsPallet,
 		BalancesPallet,
+		NonFungiblesMatcher,
+		CollectionsPallet,
 		XcmPallet,
 		AccountId,
 		Weigher,
+		DustHandler,
+		DecimalsGetter,
 	)>,
 );
 
-impl<AssetId, AssetIdInfoGetter, AssetsPallet, BalancesPallet, XcmPallet, AccountId, Weigher>
-	DropAssets
+impl<
+		AssetId,
+		AssetIdInfoGetter,
+		AssetsPallet,
+		BalancesPallet,
+		NonFungiblesMatcher,
+		CollectionsPallet,
+		XcmPallet,
+		AccountId,
+		Weigher,
+		DustHandler,
+		DecimalsGetter,
+	> DropAssets
 	for TrappistDropAssets<
 		AssetId,
 		AssetIdInfoGetter,
 		AssetsPallet,
 		BalancesPallet,
+		NonFungiblesMatcher,
+		CollectionsPallet,
 		XcmPallet,
 		AccountId,
 		Weigher,
+		DustHandler,
+		DecimalsGetter,
 	> where
 	AssetIdInfoGetter: AssetMultiLocationGetter<AssetId>,
 	AssetsPallet: Inspect<AccountId, AssetId = AssetId>,
 	BalancesPallet: Currency<AccountId>,
+	NonFungiblesMatcher:
+		MatchesNonFungibles<CollectionsPallet::CollectionId, CollectionsPallet::ItemId>,
+	CollectionsPallet: nonfungibles::Inspect<AccountId>,
 	XcmPallet: DropAssets,
 	Weigher: DropAssetsWeigher,
+	DustHandler: HandleDust,
+	DecimalsGetter: AssetDecimalsGetter<AssetId>,
 {
 	// assets are whatever the Holding Register had when XCVM halts
 	fn drop_assets(origin: &MultiLocation, mut assets: Assets, context: &XcmContext) -> Weight {
 		const NATIVE_LOCATION: MultiLocation = MultiLocation { parents: 0, interior: Here };
 
-		let mut weight: Weight = {
-			assets.non_fungible.clear();
-			Weigher::default()
-		};
+		let mut weight: Weight = Weigher::default();
+
+		assets.non_fungible.retain(|asset| {
+			weight.saturating_accrue(Weigher::non_fungible());
 
+			// only trap items of a known collection
+			// do nothing otherwise (asset is lost)
+			NonFungiblesMatcher::matches_nonfungibles(asset).map_or(false, |(collection, _)| {
+				CollectionsPallet::collection_owner(&collection).is_some()
+			})
+		});
+
+		let mut dust = Assets::new();
 		assets.fungible.retain(|id, &mut amount| {
-			if let Concrete(location) = id {
+			// whether the amount reaches the minimum balance, for known assets only
+			let above_minimum = if let Concrete(location) = id {
 				match AssetIdInfoGetter::get_asset_id(location) {
 					Some(asset_id) => {
 						weight.saturating_accrue(Weigher::fungible());
 
-						// only trap if amount ≥ min_balance
-						// do nothing otherwise (asset is lost)
-						amount.saturated_into::<AssetsPallet::Balance>()
-							>= AssetsPallet::minimum_balance(asset_id)
+						// amounts which can't be expressed in local units can't be deposited
+						let local_amount = match DecimalsGetter::get_asset_decimals(&asset_id) {
+							Some(decimals) => decimals.to_local(amount),
+							None => Some(amount),
+						};
+						Some(local_amount.map_or(false, |amount| {
+							amount.saturated_into::<AssetsPallet::Balance>()
+								>= AssetsPallet::minimum_balance(asset_id)
+						}))
 					},
 					None => {
 						weight.saturating_accrue(Weigher::native());
 
-						// only trap if native token and amount ≥ min_balance
-						// do nothing otherwise (asset is lost)
-						*location == NATIVE_LOCATION
-							&& amount.saturated_into::<BalancesPallet::Balance>()
+						(*location == NATIVE_LOCATION).then(|| {
+							amount.saturated_into::<BalancesPallet::Balance>()
 								>= BalancesPallet::minimum_balance()
+						})
 					},
 				}
 			} else {
 				weight.saturating_accrue(Weigher::default());
-				false
+				None
+			};
+
+			// only trap if amount ≥ min_balance
+			// hand the rest over to DustHandler otherwise
+			match above_minimum {
+				Some(true) => true,
+				Some(false) => {
+					dust.subsume((*id, amount).into());
+					false
+				},
+				None => {
+					DustHandler::handle_unknown(origin, id, amount);
+					false
+				},
 			}
 		});
 
+		if !dust.is_empty() {
+			DustHandler::handle_dust(origin, dust, context);
+		}
+
 		// we have filtered out non-compliant assets
 		// insert valid assets into the asset trap implemented by XcmPallet
 		weight.saturating_add(XcmPallet::drop_assets(origin, assets, context))
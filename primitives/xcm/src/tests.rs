@@ -0,0 +1,186 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{AssetDecimals, UnitsPerSecondGetter, UsingRegisteredUnitsPerSecond};
+use frame_support::weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight};
+use std::cell::RefCell;
+use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{TakeRevenue, WeightTrader},
+	Assets,
+};
+
+mod asset_decimals {
+	use super::*;
+
+	#[test]
+	fn same_decimals_are_kept() {
+		let decimals = AssetDecimals { local: 12, reserve: 12 };
+
+		assert_eq!(decimals.to_local(u128::MAX), Some(u128::MAX));
+		assert_eq!(decimals.to_reserve(1), Some(1));
+	}
+
+	#[test]
+	fn amounts_are_scaled_up() {
+		let decimals = AssetDecimals { local: 12, reserve: 6 };
+
+		assert_eq!(decimals.to_local(1_500_000), Some(1_500_000_000_000));
+		assert_eq!(decimals.to_local(0), Some(0));
+	}
+
+	#[test]
+	fn amounts_are_scaled_down_exactly() {
+		let decimals = AssetDecimals { local: 12, reserve: 6 };
+
+		assert_eq!(decimals.to_reserve(1_500_000_000_000), Some(1_500_000));
+		assert_eq!(decimals.to_reserve(0), Some(0));
+	}
+
+	#[test]
+	fn scaling_down_does_not_round() {
+		let decimals = AssetDecimals { local: 6, reserve: 12 };
+
+		// amounts losing precision are refused rather than rounded either way
+		assert_eq!(decimals.to_local(1_000_000_000_001), None);
+		assert_eq!(decimals.to_local(999_999), None);
+		assert_eq!(decimals.to_local(1_000_000), Some(1));
+	}
+
+	#[test]
+	fn scaling_up_does_not_overflow() {
+		let decimals = AssetDecimals { local: 18, reserve: 0 };
+
+		assert_eq!(
+			decimals.to_local(u128::MAX / 10u128.pow(18)),
+			Some(u128::MAX / 10u128.pow(18) * 10u128.pow(18))
+		);
+		assert_eq!(decimals.to_local(u128::MAX / 10u128.pow(18) + 1), None);
+	}
+
+	#[test]
+	fn factors_beyond_u128_are_refused() {
+		// 10^39 does not fit in a u128
+		let decimals = AssetDecimals { local: 39, reserve: 0 };
+
+		assert_eq!(decimals.to_local(1), None);
+		assert_eq!(decimals.to_reserve(u128::MAX), None);
+	}
+}
+
+mod using_registered_units_per_second {
+	use super::*;
+
+	const ASSET: MultiLocation = MultiLocation { parents: 1, interior: X1(Parachain(1000)) };
+
+	thread_local! {
+		static REVENUE: RefCell<Vec<MultiAsset>> = RefCell::new(Vec::new());
+	}
+
+	/// One unit of `ASSET` per unit of `ref_time`.
+	struct RateGetter;
+	impl UnitsPerSecondGetter for RateGetter {
+		fn get_units_per_second(asset_multi_location: &MultiLocation) -> Option<u128> {
+			(*asset_multi_location == ASSET).then_some(WEIGHT_REF_TIME_PER_SECOND.into())
+		}
+	}
+
+	struct Revenue;
+	impl TakeRevenue for Revenue {
+		fn take_revenue(revenue: MultiAsset) {
+			REVENUE.with(|taken| taken.borrow_mut().push(revenue));
+		}
+	}
+
+	type Trader = UsingRegisteredUnitsPerSecond<RateGetter, Revenue>;
+
+	fn context() -> XcmContext {
+		XcmContext::with_message_id([0; 32])
+	}
+
+	fn trader() -> Trader {
+		REVENUE.with(|taken| taken.borrow_mut().clear());
+		Trader::new()
+	}
+
+	fn payment(amount: u128) -> Assets {
+		MultiAsset::from((ASSET, amount)).into()
+	}
+
+	fn revenue() -> Vec<MultiAsset> {
+		REVENUE.with(|taken| taken.borrow_mut().drain(..).collect())
+	}
+
+	#[test]
+	fn only_ref_time_is_charged() {
+		let mut trader = trader();
+
+		let unused = trader
+			.buy_weight(Weight::from_parts(1_000, 1_000_000), payment(5_000), &context())
+			.unwrap();
+
+		assert_eq!(unused, payment(4_000));
+	}
+
+	#[test]
+	fn refunds_are_capped_to_what_is_left_after_partial_refunds() {
+		let mut trader = trader();
+		trader
+			.buy_weight(Weight::from_parts(1_000, 0), payment(5_000), &context())
+			.unwrap();
+
+		assert_eq!(
+			trader.refund_weight(Weight::from_parts(300, 0), &context()),
+			Some((ASSET, 300).into())
+		);
+		assert_eq!(
+			trader.refund_weight(Weight::from_parts(1_000, 0), &context()),
+			Some((ASSET, 700).into())
+		);
+		assert_eq!(trader.refund_weight(Weight::from_parts(1, 0), &context()), None);
+
+		drop(trader);
+		assert!(revenue().is_empty());
+	}
+
+	#[test]
+	fn weight_left_after_a_partial_refund_is_taken_as_revenue() {
+		let mut trader = trader();
+		trader
+			.buy_weight(Weight::from_parts(1_000, 0), payment(5_000), &context())
+			.unwrap();
+
+		assert_eq!(
+			trader.refund_weight(Weight::from_parts(300, 0), &context()),
+			Some((ASSET, 300).into())
+		);
+
+		drop(trader);
+		assert_eq!(revenue(), vec![(ASSET, 700).into()]);
+	}
+
+	#[test]
+	fn payments_without_a_rate_are_refused() {
+		let mut trader = trader();
+		let native: Assets = MultiAsset::from((Here, 5_000)).into();
+
+		assert_eq!(
+			trader.buy_weight(Weight::from_parts(1_000, 0), native, &context()),
+			Err(XcmError::AssetNotFound)
+		);
+	}
+}
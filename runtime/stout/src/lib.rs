@@ -222,7 +222,7 @@ impl pallet_balances::Config for Runtime {
 	type FreezeIdentifier = ();
 	type MaxLocks = ConstU32<50>;
 	type MaxReserves = ConstU32<50>;
-	type MaxHolds = ConstU32<3>;
+	type MaxHolds = ConstU32<4>;
 	type MaxFreezes = ConstU32<0>;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
@@ -426,7 +426,8 @@ impl pallet_assets::Config for Runtime {
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
 	type Extra = ();
-	type CallbackHandle = ();
+	// Destroyed assets are unregistered from the asset registry
+	type CallbackHandle = AssetRegistry;
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -543,7 +544,7 @@ impl pallet_preimage::Config for Runtime {
 #[cfg(feature = "runtime-benchmarks")]
 pub struct AssetRegistryBenchmarkHelper;
 #[cfg(feature = "runtime-benchmarks")]
-impl pallet_asset_registry::BenchmarkHelper<AssetIdForTrustBackedAssets>
+impl pallet_asset_registry::BenchmarkHelper<AssetIdForTrustBackedAssets, u32>
 	for AssetRegistryBenchmarkHelper
 {
 	fn get_registered_asset() -> AssetIdForTrustBackedAssets {
@@ -557,12 +558,48 @@ impl pallet_asset_registry::BenchmarkHelper<AssetIdForTrustBackedAssets>
 			.expect("Should have been able to force create asset");
 		asset_id
 	}
+
+	fn get_registered_collection() -> u32 {
+		use sp_runtime::traits::StaticLookup;
+
+		let root = frame_system::RawOrigin::Root.into();
+		let collection_id = 1;
+		let caller = frame_benchmarking::whitelisted_caller();
+		let caller_lookup = <Runtime as frame_system::Config>::Lookup::unlookup(caller);
+		Uniques::force_create(root, collection_id, caller_lookup, true)
+			.expect("Should have been able to force create collection");
+		collection_id
+	}
+}
+
+parameter_types! {
+	pub const AssetRegistrationDeposit: Balance = 100 * UNITS;
+	pub const AssetRegistryPalletId: PalletId = PalletId(*b"py/asreg");
+	/// Derivatives created by the registry are kept clear of locally created assets.
+	pub const DerivativeAssetIdRange: (AssetIdForTrustBackedAssets, AssetIdForTrustBackedAssets) =
+		(1_000_000_000, AssetIdForTrustBackedAssets::MAX);
+	pub const AssetFlowLimitWindow: BlockNumber = HOURS;
 }
 
 impl pallet_asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type ReserveAssetModifierOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ReserveAssetModifierOrigin =
+		frame_system::EnsureRootWithSuccess<Self::AccountId, frame_support::traits::GetDefault>;
+	type AssetId = AssetIdForTrustBackedAssets;
 	type Assets = Assets;
+	type DerivativeAssetIdRange = DerivativeAssetIdRange;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type RegistrationDeposit = AssetRegistrationDeposit;
+	type PalletId = AssetRegistryPalletId;
+	type StringLimit = ConstU32<50>;
+	type AssetLocationFilter = (
+		pallet_asset_registry::StandardAssetLocations,
+		pallet_asset_registry::BridgedAssetLocations<pallet_asset_registry::StandardAssetLocations>,
+	);
+	type FlowLimitWindow = AssetFlowLimitWindow;
+	type CollectionId = u32;
+	type Collections = Uniques;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = AssetRegistryBenchmarkHelper;
@@ -611,7 +648,7 @@ construct_runtime!(
 		Multisig: pallet_multisig = 49,
 
 		Spambot: cumulus_ping::{Pallet, Call, Storage, Event<T>} = 99,
-		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Event<T>} = 111,
+		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Event<T>, Config<T>, HoldReason} = 111,
 	}
 );
 
@@ -775,6 +812,26 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_asset_registry_runtime_api::AssetRegistryApi<Block, AssetIdForTrustBackedAssets> for Runtime {
+		fn asset_location(asset_id: AssetIdForTrustBackedAssets) -> Option<xcm::VersionedMultiLocation> {
+			AssetRegistry::asset_location(asset_id)
+		}
+
+		fn asset_id(location: xcm::VersionedMultiLocation) -> Option<AssetIdForTrustBackedAssets> {
+			AssetRegistry::asset_id(location)
+		}
+
+		fn registered_assets() -> Vec<(AssetIdForTrustBackedAssets, xcm::VersionedMultiLocation)> {
+			AssetRegistry::registered_assets()
+		}
+
+		fn assets_by_reserve(
+			reserve: xcm::VersionedMultiLocation,
+		) -> Vec<(AssetIdForTrustBackedAssets, xcm::VersionedMultiLocation)> {
+			AssetRegistry::assets_by_reserve(reserve)
+		}
+	}
+
 
 	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord> for Runtime {
 		fn call(
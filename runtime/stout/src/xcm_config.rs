@@ -24,12 +24,11 @@ use super::{
 };
 use frame_support::{
 	match_types, parameter_types,
-	traits::{ContainsPair, EitherOfDiverse, Everything, Get, Nothing},
+	traits::{EitherOfDiverse, Everything, Nothing},
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
 use sp_core::ConstU32;
-use sp_std::marker::PhantomData;
 
 use parachains_common::{impls::DealWithFees, AssetIdForTrustBackedAssets};
 
@@ -38,8 +37,11 @@ use xcm_executor::traits::JustTry;
 use pallet_xcm::{EnsureXcm, IsMajorityOfBody, XcmPassthrough};
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
-use xcm::latest::{prelude::*, MultiAsset, MultiLocation};
-use xcm_primitives::{AsAssetMultiLocation, ConvertedRegisteredAssetId};
+use xcm::latest::{prelude::*, MultiLocation};
+use xcm_primitives::{
+	AsAssetMultiLocation, ConvertedRegisteredAssetId, FlowLimitedTransactor, RegisteredReserves,
+	UsingRegisteredUnitsPerSecond,
+};
 
 use parachains_common::message_queue::ParaIdToSibling;
 use xcm_builder::{
@@ -137,6 +139,10 @@ pub type ReservedFungiblesTransactor = FungiblesAdapter<
 		Balance,
 		AsAssetMultiLocation<AssetIdForTrustBackedAssets, AssetRegistry>,
 		JustTry,
+		// Scale amounts when the reserve uses different decimals than the derivative
+		AssetRegistry,
+		// Refuse assets frozen or quarantined in the registry
+		AssetRegistry,
 	>,
 	// Convert an XCM MultiLocation into a local account id:
 	LocationToAccountId,
@@ -150,7 +156,12 @@ pub type ReservedFungiblesTransactor = FungiblesAdapter<
 >;
 
 /// Means for transacting assets on this chain.
-pub type AssetTransactors = (CurrencyTransactor, ReservedFungiblesTransactor, FungiblesTransactor);
+pub type AssetTransactors = (
+	CurrencyTransactor,
+	// Deposits and withdrawals of registered assets are subject to their flow limits
+	FlowLimitedTransactor<ReservedFungiblesTransactor, AssetRegistry>,
+	FungiblesTransactor,
+);
 
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
@@ -239,15 +250,6 @@ parameter_types! {
 		MultiLocation::new(1, X2(Parachain(1000), PalletInstance(50)));
 }
 
-pub struct ReserveAssetsFrom<T>(PhantomData<T>);
-impl<T: Get<MultiLocation>> ContainsPair<MultiAsset, MultiLocation> for ReserveAssetsFrom<T> {
-	fn contains(_asset: &MultiAsset, origin: &MultiLocation) -> bool {
-		let prefix = T::get();
-		log::trace!(target: "xcm::AssetsFrom", "prefix: {:?}, origin: {:?}", prefix, origin);
-		&prefix == origin
-	}
-}
-
 pub type Traders = (
 	// RUSD
 	FixedRateOfFungible<RUsdPerSecond, ()>,
@@ -255,13 +257,15 @@ pub type Traders = (
 	FixedRateOfFungible<RocPerSecond, ()>,
 	//HOP
 	FixedRateOfFungible<HopPerSecond, ()>,
+	// Registered assets priced on-chain
+	UsingRegisteredUnitsPerSecond<AssetRegistry, ()>,
 	// Everything else
 	UsingComponents<WeightToFee, SelfReserve, AccountId, Balances, DealWithFees<Runtime>>,
 );
 
 //--
 
-pub type Reserves = (NativeAsset, ReserveAssetsFrom<StatemineLocation>);
+pub type Reserves = (NativeAsset, RegisteredReserves<AssetRegistry>);
 
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
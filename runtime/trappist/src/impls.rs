@@ -21,6 +21,7 @@
 use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
 pub use log;
 use sp_std::marker::PhantomData;
+use xcm_primitives::PauseXcmExecution;
 
 /// Type alias to conveniently refer to the `Currency::NegativeImbalance` associated type.
 pub type NegativeImbalance<T> = <pallet_balances::Pallet<T> as Currency<
@@ -69,6 +70,22 @@ where
 	}
 }
 
+/// Suspends the execution of inbound XCM while the chain is in safe mode, and resumes it on exit.
+pub struct SuspendXcmOnSafeMode<Manager>(PhantomData<Manager>);
+impl<Manager: PauseXcmExecution> pallet_safe_mode::Notify for SuspendXcmOnSafeMode<Manager> {
+	fn entered() {
+		if let Err(error) = Manager::suspend_xcm_execution() {
+			log::error!(target: "runtime::safe-mode", "failed to suspend XCM execution: {error:?}");
+		}
+	}
+
+	fn exited() {
+		if let Err(error) = Manager::resume_xcm_execution() {
+			log::error!(target: "runtime::safe-mode", "failed to resume XCM execution: {error:?}");
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use frame_support::traits::tokens::{PayFromAccount, UnityAssetBalanceConversion};
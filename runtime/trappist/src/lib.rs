@@ -31,8 +31,8 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		tokens::{PayFromAccount, UnityAssetBalanceConversion},
-		AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, Contains, EitherOfDiverse,
-		EqualPrivilegeOnly,
+		AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, Contains, EitherOf,
+		EitherOfDiverse, EqualPrivilegeOnly, Equals, Everything, GetDefault, InsideBoth,
 	},
 	weights::{constants::RocksDbWeight, ConstantMultiplier, Weight},
 	PalletId,
@@ -73,9 +73,10 @@ use sp_version::RuntimeVersion;
 use xcm::latest::{prelude::BodyId, InteriorMultiLocation, Junction::PalletInstance};
 
 use constants::{currency::*, fee::WeightToFee};
-use impls::DealWithFees;
+use impls::{DealWithFees, SuspendXcmOnSafeMode};
 use xcm_config::{
-	CollatorSelectionUpdateOrigin, RelayLocation, TrustBackedAssetsConvertedConcreteId,
+	AssetTransactors, CollatorSelectionUpdateOrigin, LocalOriginToLocation, RelayLocation,
+	SiblingParachains, TreasuryAccountLocation, TrustBackedAssetsConvertedConcreteId,
 };
 
 use crate::weights::{block_weights::BlockExecutionWeight, extrinsic_weights::ExtrinsicBaseWeight};
@@ -87,6 +88,8 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 pub mod constants;
 mod contracts;
 pub mod impls;
+#[cfg(test)]
+mod tests;
 mod weights;
 pub mod xcm_config;
 
@@ -146,7 +149,51 @@ impl frame_support::traits::OnRuntimeUpgrade for FixStorageVersions {
 	}
 }
 
-pub type Migrations = FixStorageVersions;
+/// Tops the asset registry account up to `AssetRegistryAccountFunding` from the treasury.
+///
+/// The registry account pays the `Assets` metadata deposits of the derivatives it creates, but
+/// chains started before it did were not endowed with it at genesis. It only runs once, and can be
+/// removed from `Migrations` after the upgrade to spec version 15001.
+pub struct FundAssetRegistryAccount;
+
+/// Set once `FundAssetRegistryAccount` ran, so later upgrades don't draw on the treasury again.
+#[frame_support::storage_alias]
+type AssetRegistryAccountFunded = StorageValue<AssetRegistry, bool, ValueQuery>;
+
+impl frame_support::traits::OnRuntimeUpgrade for FundAssetRegistryAccount {
+	fn on_runtime_upgrade() -> Weight {
+		use frame_support::traits::{
+			fungible::{Inspect, Mutate},
+			tokens::Preservation,
+		};
+
+		if AssetRegistryAccountFunded::get() {
+			return <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+		}
+		AssetRegistryAccountFunded::put(true);
+
+		let registry = AssetRegistry::account_id();
+		let shortfall =
+			AssetRegistryAccountFunding::get().saturating_sub(Balances::balance(&registry));
+		if shortfall > 0 {
+			if let Err(error) = <Balances as Mutate<_>>::transfer(
+				&TreasuryAccount::get(),
+				&registry,
+				shortfall,
+				Preservation::Preserve,
+			) {
+				log::warn!(
+					target: "runtime::asset-registry",
+					"failed to fund the asset registry account: {error:?}"
+				);
+			}
+		}
+
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 3)
+	}
+}
+
+pub type Migrations = (FixStorageVersions, FundAssetRegistryAccount);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -169,10 +216,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("trappist-rococo"),
 	impl_name: create_runtime_str!("trappist-rococo"),
 	authoring_version: 1,
-	spec_version: 15000,
+	spec_version: 15001,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 3,
+	transaction_version: 4,
 	state_version: 1,
 };
 
@@ -209,6 +256,7 @@ parameter_types! {
 // Configure FRAME pallets to include in runtime.
 #[derive_impl(frame_system::config_preludes::ParaChainDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
+	type BaseCallFilter = InsideBoth<SafeMode, TxPause>;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type Nonce = Nonce;
@@ -255,7 +303,7 @@ impl pallet_balances::Config for Runtime {
 	type FreezeIdentifier = ();
 	type MaxLocks = ConstU32<50>;
 	type MaxReserves = ConstU32<50>;
-	type MaxHolds = ConstU32<3>;
+	type MaxHolds = ConstU32<4>;
 	type MaxFreezes = ConstU32<0>;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
@@ -433,7 +481,8 @@ impl pallet_assets::Config for Runtime {
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
 	type Extra = ();
-	type CallbackHandle = ();
+	// Destroyed assets are unregistered from the asset registry
+	type CallbackHandle = AssetRegistry;
 	type WeightInfo = weights::pallet_assets::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -625,7 +674,8 @@ impl pallet_message_queue::Config for Runtime {
 	type Size = u32;
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
-	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	// Queues are also paused while in safe mode, or by governance.
+	type QueuePausedQuery = (NarrowOriginToSibling<XcmpQueue>, XcmPause);
 	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -634,7 +684,7 @@ impl pallet_message_queue::Config for Runtime {
 #[cfg(feature = "runtime-benchmarks")]
 pub struct AssetRegistryBenchmarkHelper;
 #[cfg(feature = "runtime-benchmarks")]
-impl pallet_asset_registry::BenchmarkHelper<AssetIdForTrustBackedAssets>
+impl pallet_asset_registry::BenchmarkHelper<AssetIdForTrustBackedAssets, u32>
 	for AssetRegistryBenchmarkHelper
 {
 	fn get_registered_asset() -> AssetIdForTrustBackedAssets {
@@ -648,12 +698,55 @@ impl pallet_asset_registry::BenchmarkHelper<AssetIdForTrustBackedAssets>
 			.expect("Should have been able to force create asset");
 		asset_id
 	}
+
+	fn get_registered_collection() -> u32 {
+		use sp_runtime::traits::StaticLookup;
+
+		let root = frame_system::RawOrigin::Root.into();
+		let collection_id = 1;
+		let caller = frame_benchmarking::whitelisted_caller();
+		let caller_lookup = <Runtime as frame_system::Config>::Lookup::unlookup(caller);
+		Uniques::force_create(root, collection_id, caller_lookup, true)
+			.expect("Should have been able to force create collection");
+		collection_id
+	}
+}
+
+/// Root, or a sibling parachain managing the assets it is the reserve of.
+pub type ReserveAssetModifierOrigin = EitherOf<
+	EnsureRootWithSuccess<AccountId, GetDefault>,
+	pallet_asset_registry::EnsureReserveChain<EnsureXcm<SiblingParachains>>,
+>;
+
+parameter_types! {
+	pub const AssetRegistrationDeposit: Balance = 100 * UNITS;
+	pub const AssetRegistryPalletId: PalletId = PalletId(*b"py/asreg");
+	/// Kept in the registry account, which pays the metadata deposits of the derivatives.
+	pub const AssetRegistryAccountFunding: Balance = 100 * UNITS;
+	/// Derivatives created by the registry are kept clear of locally created assets.
+	pub const DerivativeAssetIdRange: (AssetIdForTrustBackedAssets, AssetIdForTrustBackedAssets) =
+		(1_000_000_000, AssetIdForTrustBackedAssets::MAX);
+	pub const AssetFlowLimitWindow: BlockNumber = HOURS;
 }
 
 impl pallet_asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type ReserveAssetModifierOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ReserveAssetModifierOrigin = ReserveAssetModifierOrigin;
+	type AssetId = AssetIdForTrustBackedAssets;
 	type Assets = Assets;
+	type DerivativeAssetIdRange = DerivativeAssetIdRange;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type RegistrationDeposit = AssetRegistrationDeposit;
+	type PalletId = AssetRegistryPalletId;
+	type StringLimit = ConstU32<50>;
+	type AssetLocationFilter = (
+		pallet_asset_registry::StandardAssetLocations,
+		pallet_asset_registry::BridgedAssetLocations<pallet_asset_registry::StandardAssetLocations>,
+	);
+	type FlowLimitWindow = AssetFlowLimitWindow;
+	type CollectionId = u32;
+	type Collections = Uniques;
 	type WeightInfo = weights::pallet_asset_registry::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = AssetRegistryBenchmarkHelper;
@@ -741,19 +834,96 @@ impl pallet_treasury::Config for Runtime {
 	type BenchmarkHelper = treasury_benchmark_helper::TreasuryBenchmarkHelper;
 }
 
+parameter_types! {
+	/// Weight bought per instruction of the messages `WithdrawTeleport` sends, by destination.
+	///
+	/// The relay chain weighs each instruction at this fixed `BaseXcmWeight`. Asset Hub uses
+	/// benchmarked weights, all below it for the instructions sent, so the estimate errs on the
+	/// high side. The surplus is refunded to the beneficiary on destination.
+	pub DestinationUnitWeights: Vec<(xcm::latest::MultiLocation, Weight)> = vec![
+		(RelayLocation::get(), Weight::from_parts(1_000_000_000, 64 * 1024)),
+		(xcm_config::AssetHubLocation::get(), Weight::from_parts(1_000_000_000, 64 * 1024)),
+	];
+	pub const WithdrawTeleportResponseTimeout: BlockNumber = HOURS;
+}
+
 impl pallet_withdraw_teleport::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type DestinationWeigher =
+		pallet_withdraw_teleport::FixedWeightPerInstruction<DestinationUnitWeights>;
+	type DestinationFees =
+		pallet_withdraw_teleport::FixedDestinationRates<xcm_config::DestinationFeeRates>;
+	type RuntimeCall = RuntimeCall;
+	type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type AssetTransactor = AssetTransactors;
+	type ResponseTimeout = WithdrawTeleportResponseTimeout;
+	type TrustedDestinations = (Equals<RelayLocation>, Equals<xcm_config::AssetHubLocation>);
+	type ExpireOrigin = EnsureRootOrHalfCouncil;
 	type WeightInfo = weights::pallet_withdraw_teleport::WeightInfo<Runtime>;
 }
 
-/// Calls that can bypass the safe-mode pallet.
+parameter_types! {
+	pub const TrapExpiry: BlockNumber = 30 * DAYS;
+	pub const SweepReward: Perbill = Perbill::from_percent(1);
+	pub const IdleSweepDelay: BlockNumber = 7 * DAYS;
+	pub const MaxSweepsPerBlock: u32 = 10;
+}
+
+impl pallet_asset_trap::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetTrap = PolkadotXcm;
+	type AssetClaims = PolkadotXcm;
+	type AssetTransactor = xcm_config::TrappedAssetTransactors;
+	type ClaimOrigin = xcm_builder::EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type TreasuryLocation = TreasuryAccountLocation;
+	type AccountIdToLocation = xcm_builder::AliasesIntoAccountId32<(), AccountId>;
+	type TrapExpiry = TrapExpiry;
+	type SweepReward = SweepReward;
+	type IdleSweepDelay = IdleSweepDelay;
+	type MaxSweepsPerBlock = MaxSweepsPerBlock;
+	type WeightInfo = weights::pallet_asset_trap::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetTrapBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetTrapBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_asset_trap::BenchmarkHelper for AssetTrapBenchmarkHelper {
+	fn fungible_asset(index: u32) -> xcm::latest::MultiAsset {
+		use frame_support::traits::fungibles::Create;
+		use xcm::latest::prelude::*;
+
+		// sufficient, so the treasury and the sweeper can receive it without an account
+		let asset_id = index + 1;
+		let owner = frame_benchmarking::whitelisted_caller();
+		<Assets as Create<AccountId>>::create(asset_id, owner, true, 1)
+			.expect("Should have been able to create asset");
+		let location = xcm_config::AssetsPalletLocation::get()
+			.pushed_with_interior(GeneralIndex(asset_id.into()))
+			.expect("Should have been able to build the asset location");
+		(location, 1_000_000_000_000u128).into()
+	}
+}
+
+/// Calls that can bypass the safe-mode pallet: the inherents, so blocks can still be built, and
+/// governance, so it can still act while in safe mode.
 pub struct SafeModeWhitelistedCalls;
 impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
 	fn contains(call: &RuntimeCall) -> bool {
 		match call {
 			RuntimeCall::System(_)
+			| RuntimeCall::ParachainSystem(_)
+			| RuntimeCall::Timestamp(_)
+			| RuntimeCall::Council(_)
+			| RuntimeCall::Democracy(_)
+			| RuntimeCall::Preimage(_)
+			| RuntimeCall::Scheduler(_)
+			| RuntimeCall::Sudo(_)
 			| RuntimeCall::SafeMode(_)
 			| RuntimeCall::TxPause(_)
+			| RuntimeCall::XcmPause(_)
 			| RuntimeCall::Balances(_) => true,
 			_ => false,
 		}
@@ -781,18 +951,22 @@ impl pallet_safe_mode::Config for Runtime {
 	type ForceExtendOrigin = EnsureRootWithSuccess<AccountId, ConstU32<11>>;
 	type ForceExitOrigin = EnsureRoot<AccountId>;
 	type ForceDepositOrigin = EnsureRoot<AccountId>;
-	type Notify = ();
+	type Notify = SuspendXcmOnSafeMode<XcmPause>;
 	type ReleaseDelay = ReleaseDelay;
 	type WeightInfo = weights::pallet_safe_mode::WeightInfo<Runtime>;
 }
 
 /// Calls that cannot be paused by the tx-pause pallet.
 pub struct TxPauseWhitelistedCalls;
-/// Whitelist `Balances::transfer_keep_alive`, all others are pauseable.
+/// Whitelist `Balances::transfer_keep_alive`, the inherents, `Sudo` and the `XcmPause` calls, all
+/// others are pauseable.
 impl Contains<RuntimeCallNameOf<Runtime>> for TxPauseWhitelistedCalls {
 	fn contains(full_name: &RuntimeCallNameOf<Runtime>) -> bool {
 		match (full_name.0.as_slice(), full_name.1.as_slice()) {
 			(b"Balances", b"transfer_keep_alive") => true,
+			(b"ParachainSystem", _) | (b"Timestamp", _) => true,
+			(b"Sudo", _) => true,
+			(b"XcmPause", _) => true,
 			_ => false,
 		}
 	}
@@ -808,6 +982,13 @@ impl pallet_tx_pause::Config for Runtime {
 	type WeightInfo = weights::pallet_tx_pause::WeightInfo<Runtime>;
 }
 
+impl pallet_xcm_pause::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MessageOrigin = AggregateMessageOrigin;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::pallet_xcm_pause::WeightInfo<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub struct Runtime {
@@ -861,6 +1042,8 @@ construct_runtime!(
 		// Additional pallets
 		AssetRegistry: pallet_asset_registry = 111,
 		WithdrawTeleport: pallet_withdraw_teleport = 112,
+		AssetTrap: pallet_asset_trap = 113,
+		XcmPause: pallet_xcm_pause = 114,
 	}
 );
 
@@ -890,6 +1073,8 @@ mod benches {
 		[pallet_utility, Utility]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_withdraw_teleport, WithdrawTeleport]
+		[pallet_asset_trap, AssetTrap]
+		[pallet_xcm_pause, XcmPause]
 		// XCM
 		// NOTE: Make sure you point to the individual modules below.
 		[pallet_xcm_benchmarks::fungible, XcmBalances]
@@ -1036,6 +1221,60 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_asset_registry_runtime_api::AssetRegistryApi<Block, AssetIdForTrustBackedAssets> for Runtime {
+		fn asset_location(asset_id: AssetIdForTrustBackedAssets) -> Option<xcm::VersionedMultiLocation> {
+			AssetRegistry::asset_location(asset_id)
+		}
+
+		fn asset_id(location: xcm::VersionedMultiLocation) -> Option<AssetIdForTrustBackedAssets> {
+			AssetRegistry::asset_id(location)
+		}
+
+		fn registered_assets() -> Vec<(AssetIdForTrustBackedAssets, xcm::VersionedMultiLocation)> {
+			AssetRegistry::registered_assets()
+		}
+
+		fn assets_by_reserve(
+			reserve: xcm::VersionedMultiLocation,
+		) -> Vec<(AssetIdForTrustBackedAssets, xcm::VersionedMultiLocation)> {
+			AssetRegistry::assets_by_reserve(reserve)
+		}
+	}
+
+	impl pallet_asset_trap_runtime_api::AssetTrapApi<Block, BlockNumber> for Runtime {
+		fn traps(
+			origin: xcm::VersionedMultiLocation,
+		) -> Vec<(sp_core::H256, xcm::VersionedMultiAssets, u32)> {
+			AssetTrap::traps(origin)
+		}
+
+		fn trap_expiry(hash: sp_core::H256) -> Option<BlockNumber> {
+			AssetTrap::trap_expiry(hash)
+		}
+	}
+
+	impl pallet_withdraw_teleport_runtime_api::WithdrawTeleportApi<Block> for Runtime {
+		fn dry_run_withdraw_and_teleport(
+			origin: xcm::VersionedMultiLocation,
+			dest: xcm::VersionedMultiLocation,
+			beneficiary: xcm::VersionedMultiLocation,
+			assets: xcm::VersionedMultiAssets,
+			fee_assets: xcm::VersionedMultiAssets,
+			fee_asset_item: u32,
+			weight_limit: Option<xcm::v3::WeightLimit>,
+		) -> Result<pallet_withdraw_teleport::DryRunEffects, sp_runtime::DispatchError> {
+			WithdrawTeleport::dry_run_withdraw_and_teleport(
+				origin,
+				dest,
+				beneficiary,
+				assets,
+				fee_assets,
+				fee_asset_item,
+				weight_limit,
+			)
+		}
+	}
+
 	impl assets_common::runtime_api::FungiblesApi<
 		Block,
 		AccountId,
@@ -1322,6 +1561,7 @@ impl_runtime_apis! {
 			use xcm_primitives::TrappistDropAssets;
 			use xcm::prelude::MultiLocation;
 			use crate::weights::TrappistDropAssetsWeigher;
+			use crate::xcm_config::TrappableNonFungibles;
 
 			use parachains_common::AssetIdForTrustBackedAssets as TrappistAssetId;
 
@@ -1329,12 +1569,23 @@ impl_runtime_apis! {
 				type AssetId = TrappistAssetId;
 				type Balance = Balance;
 				type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
-				type DropAssets = TrappistDropAssets<TrappistAssetId, AssetRegistry, Assets, Balances, (), AccountId, TrappistDropAssetsWeigher>;
+				type DropAssets = TrappistDropAssets<TrappistAssetId, AssetRegistry, Assets, Balances, TrappableNonFungibles, Uniques, (), AccountId, TrappistDropAssetsWeigher, ()>;
 
 				fn register_asset(asset_id: Self::AssetId, location: MultiLocation) {
 					pallet_asset_registry::AssetMultiLocationId::<Runtime>::insert(location, asset_id);
 					pallet_asset_registry::AssetIdMultiLocation::<Runtime>::insert(asset_id, location);
 				}
+
+				fn register_collection(location: MultiLocation) {
+					use sp_runtime::traits::StaticLookup;
+
+					let collection_id = 1;
+					let owner = <Runtime as frame_system::Config>::Lookup::unlookup(frame_benchmarking::whitelisted_caller());
+					Uniques::force_create(frame_system::RawOrigin::Root.into(), collection_id, owner, true)
+						.expect("Should have been able to force create collection");
+					pallet_asset_registry::CollectionMultiLocationId::<Runtime>::insert(location, collection_id);
+					pallet_asset_registry::CollectionIdMultiLocation::<Runtime>::insert(collection_id, location);
+				}
 			}
 
 			let whitelist: Vec<TrackedStorageKey> = vec![
@@ -0,0 +1,423 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	xcm_config::XcmConfig, AccountId, Assets, Balances, BuildStorage, ExistentialDeposit, Runtime,
+	RuntimeOrigin, System, TreasuryAccount, Uniques,
+};
+use frame_support::traits::{
+	fungible::{Inspect as _, Mutate as _},
+	fungibles::{Create, Mutate},
+	Get,
+};
+use pallet_asset_registry::{AssetIdFlow, AssetIdFlowLimits, AssetIdStatus, FlowLimits};
+use sp_runtime::traits::StaticLookup;
+use xcm::latest::prelude::*;
+use xcm_executor::{traits::DropAssets, Assets as HoldingAssets};
+use xcm_primitives::AssetStatus;
+
+type AssetTrap = <XcmConfig as xcm_executor::Config>::AssetTrap;
+
+const ASSET_ID: u32 = 1;
+const ASSET_MIN_BALANCE: u128 = 1_000;
+const ASSET_LOCATION: MultiLocation = MultiLocation {
+	parents: 1,
+	interior: X3(Parachain(1000), PalletInstance(50), GeneralIndex(1)),
+};
+const ASSET_OWNER: AccountId = AccountId::new([1; 32]);
+const COLLECTION_ID: u32 = 1;
+const COLLECTION_LOCATION: MultiLocation = MultiLocation {
+	parents: 1,
+	interior: X3(Parachain(1000), PalletInstance(51), GeneralIndex(COLLECTION_ID as u128)),
+};
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn register_asset(asset_id: u32, location: MultiLocation) {
+	<Assets as Create<AccountId>>::create(asset_id, ASSET_OWNER, true, ASSET_MIN_BALANCE).unwrap();
+	pallet_asset_registry::AssetMultiLocationId::<Runtime>::insert(location, asset_id);
+	pallet_asset_registry::AssetIdMultiLocation::<Runtime>::insert(asset_id, location);
+}
+
+fn register_collection(collection_id: u32, location: MultiLocation) {
+	let owner = <Runtime as frame_system::Config>::Lookup::unlookup(ASSET_OWNER);
+	Uniques::force_create(RuntimeOrigin::root(), collection_id, owner, true).unwrap();
+	pallet_asset_registry::CollectionMultiLocationId::<Runtime>::insert(location, collection_id);
+	pallet_asset_registry::CollectionIdMultiLocation::<Runtime>::insert(collection_id, location);
+}
+
+fn context() -> XcmContext {
+	XcmContext::with_message_id([0; 32])
+}
+
+mod asset_trap {
+	use super::*;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn dust_is_deposited_into_the_treasury() {
+		new_test_ext().execute_with(|| {
+			let treasury = TreasuryAccount::get();
+			let native_dust = ExistentialDeposit::get() / 2;
+			let asset_dust = ASSET_MIN_BALANCE / 2;
+
+			register_asset(ASSET_ID, ASSET_LOCATION);
+			// the treasury can only receive dust into accounts which exist
+			Balances::set_balance(&treasury, ExistentialDeposit::get());
+			Assets::mint_into(ASSET_ID, &treasury, ASSET_MIN_BALANCE).unwrap();
+			// any deposit counted against this limit would quarantine the asset
+			AssetIdFlowLimits::<Runtime>::insert(
+				ASSET_ID,
+				FlowLimits { inbound: Some(1), outbound: None, quarantine: true },
+			);
+
+			let mut dust = HoldingAssets::new();
+			dust.subsume((Here, native_dust).into());
+			dust.subsume((ASSET_LOCATION, asset_dust).into());
+			AssetTrap::drop_assets(&MultiLocation::new(1, X1(Parachain(1000))), dust, &context());
+
+			assert_eq!(Balances::balance(&treasury), ExistentialDeposit::get() + native_dust);
+			assert_eq!(Assets::balance(ASSET_ID, &treasury), ASSET_MIN_BALANCE + asset_dust);
+			assert_eq!(AssetIdFlow::<Runtime>::get(ASSET_ID).inbound, 0);
+			assert_eq!(AssetIdStatus::<Runtime>::get(ASSET_ID), AssetStatus::Active);
+		});
+	}
+
+	#[test]
+	fn dust_is_measured_in_local_units() {
+		new_test_ext().execute_with(|| {
+			let treasury = TreasuryAccount::get();
+			let origin = MultiLocation::new(1, X1(Parachain(1000)));
+
+			register_asset(ASSET_ID, ASSET_LOCATION);
+			// a reserve unit is worth 10^-6 local units
+			pallet_asset_registry::AssetIdMetadata::<Runtime>::insert(
+				ASSET_ID,
+				pallet_asset_registry::AssetMetadata {
+					name: b"Reserve Token".to_vec().try_into().unwrap(),
+					symbol: b"RES".to_vec().try_into().unwrap(),
+					decimals: 6,
+					reserve_decimals: 12,
+				},
+			);
+			Assets::mint_into(ASSET_ID, &treasury, ASSET_MIN_BALANCE).unwrap();
+
+			// above the minimum balance in reserve units, below it in local ones
+			let dust = ASSET_MIN_BALANCE * 1_000_000 / 2;
+			AssetTrap::drop_assets(&origin, (ASSET_LOCATION, dust).into(), &context());
+			assert_eq!(Assets::balance(ASSET_ID, &treasury), ASSET_MIN_BALANCE * 3 / 2);
+			assert_eq!(pallet_asset_trap::Traps::<Runtime>::iter().count(), 0);
+
+			let trapped = ASSET_MIN_BALANCE * 1_000_000;
+			AssetTrap::drop_assets(&origin, (ASSET_LOCATION, trapped).into(), &context());
+			assert_eq!(Assets::balance(ASSET_ID, &treasury), ASSET_MIN_BALANCE * 3 / 2);
+			assert_eq!(pallet_asset_trap::Traps::<Runtime>::iter().count(), 1);
+		});
+	}
+
+	#[test]
+	fn claims_bypass_flow_limits() {
+		new_test_ext().execute_with(|| {
+			register_asset(ASSET_ID, ASSET_LOCATION);
+			// any deposit counted against this limit would quarantine the asset
+			AssetIdFlowLimits::<Runtime>::insert(
+				ASSET_ID,
+				FlowLimits { inbound: Some(1), outbound: None, quarantine: true },
+			);
+			AssetIdStatus::<Runtime>::insert(ASSET_ID, AssetStatus::Deprecated);
+
+			let origin = MultiLocation::new(1, X1(Parachain(1000)));
+			let trapped: MultiAsset = (ASSET_LOCATION, ASSET_MIN_BALANCE * 10).into();
+			AssetTrap::drop_assets(&origin, trapped.clone().into(), &context());
+
+			let beneficiary: MultiLocation = AccountId32 { network: None, id: [2; 32] }.into();
+			assert_ok!(pallet_asset_trap::Pallet::<Runtime>::claim_trapped(
+				RuntimeOrigin::root(),
+				Box::new(origin.into()),
+				Box::new(MultiAssets::from(trapped).into()),
+				Box::new(beneficiary.into()),
+			));
+
+			assert_eq!(Assets::balance(ASSET_ID, AccountId::new([2; 32])), ASSET_MIN_BALANCE * 10);
+			assert_eq!(AssetIdFlow::<Runtime>::get(ASSET_ID).inbound, 0);
+			assert_eq!(AssetIdStatus::<Runtime>::get(ASSET_ID), AssetStatus::Deprecated);
+		});
+	}
+
+	#[test]
+	fn items_of_registered_collections_are_trapped_and_claimed() {
+		new_test_ext().execute_with(|| {
+			register_collection(COLLECTION_ID, COLLECTION_LOCATION);
+
+			let origin = MultiLocation::new(1, X1(Parachain(1000)));
+			let item: MultiAsset = (COLLECTION_LOCATION, Index(7)).into();
+			let unknown: MultiAsset = (MultiLocation::new(1, X1(Parachain(2000))), Index(7)).into();
+			let mut holding = HoldingAssets::new();
+			holding.subsume(item.clone());
+			holding.subsume(unknown);
+			AssetTrap::drop_assets(&origin, holding, &context());
+
+			// items of unknown collections are lost
+			let traps = pallet_asset_trap::Pallet::<Runtime>::traps(origin.into());
+			assert_eq!(traps.len(), 1);
+			let (_, trapped, _) = &traps[0];
+			assert_eq!(*trapped, MultiAssets::from(item.clone()).into());
+
+			let beneficiary: MultiLocation = AccountId32 { network: None, id: [2; 32] }.into();
+			assert_ok!(pallet_asset_trap::Pallet::<Runtime>::claim_trapped(
+				RuntimeOrigin::root(),
+				Box::new(origin.into()),
+				Box::new(trapped.clone()),
+				Box::new(beneficiary.into()),
+			));
+
+			assert_eq!(Uniques::owner(COLLECTION_ID, 7), Some(AccountId::new([2; 32])));
+			assert!(pallet_asset_trap::Pallet::<Runtime>::traps(origin.into()).is_empty());
+		});
+	}
+}
+
+mod non_fungibles {
+	use super::*;
+	use crate::xcm_config::AssetTransactors;
+	use xcm_executor::traits::TransactAsset;
+
+	fn holder() -> MultiLocation {
+		AccountId32 { network: None, id: [2; 32] }.into()
+	}
+
+	#[test]
+	fn items_of_registered_collections_are_deposited_and_withdrawn() {
+		new_test_ext().execute_with(|| {
+			register_collection(COLLECTION_ID, COLLECTION_LOCATION);
+			let item: MultiAsset = (COLLECTION_LOCATION, Index(7)).into();
+
+			// received from the reserve
+			assert_ok!(AssetTransactors::deposit_asset(&item, &holder(), Some(&context())));
+			assert_eq!(Uniques::owner(COLLECTION_ID, 7), Some(AccountId::new([2; 32])));
+
+			// sent back to the reserve
+			let withdrawn =
+				AssetTransactors::withdraw_asset(&item, &holder(), Some(&context())).unwrap();
+			assert_eq!(withdrawn, HoldingAssets::from(item));
+			assert_eq!(Uniques::owner(COLLECTION_ID, 7), None);
+		});
+	}
+
+	#[test]
+	fn items_of_unknown_collections_are_not_transacted() {
+		new_test_ext().execute_with(|| {
+			register_collection(COLLECTION_ID, COLLECTION_LOCATION);
+			let unknown: MultiAsset = (MultiLocation::new(1, X1(Parachain(2000))), Index(7)).into();
+
+			assert!(AssetTransactors::deposit_asset(&unknown, &holder(), Some(&context())).is_err());
+			assert!(AssetTransactors::withdraw_asset(&unknown, &holder(), None).is_err());
+		});
+	}
+
+	#[test]
+	fn items_are_only_withdrawn_from_their_owner() {
+		new_test_ext().execute_with(|| {
+			register_collection(COLLECTION_ID, COLLECTION_LOCATION);
+			let item: MultiAsset = (COLLECTION_LOCATION, Index(7)).into();
+			assert_ok!(AssetTransactors::deposit_asset(&item, &holder(), Some(&context())));
+
+			let other: MultiLocation = AccountId32 { network: None, id: [3; 32] }.into();
+			assert!(AssetTransactors::withdraw_asset(&item, &other, None).is_err());
+			assert_eq!(Uniques::owner(COLLECTION_ID, 7), Some(AccountId::new([2; 32])));
+		});
+	}
+}
+
+mod safe_mode {
+	use super::*;
+	use crate::{SafeMode, XcmPause};
+	use cumulus_primitives_core::AggregateMessageOrigin;
+	use frame_support::{
+		assert_ok,
+		traits::{OnInitialize, QueuePausedQuery},
+	};
+	use pallet_xcm_pause::{Event as XcmPauseEvent, XcmExecutionSuspended};
+
+	type QueuePaused = <Runtime as pallet_message_queue::Config>::QueuePausedQuery;
+
+	#[test]
+	fn xcm_is_suspended_each_time_safe_mode_is_entered() {
+		new_test_ext().execute_with(|| {
+			for _ in 0..2 {
+				assert_ok!(SafeMode::force_enter(RuntimeOrigin::root()));
+				assert!(XcmExecutionSuspended::<Runtime>::get());
+				System::assert_has_event(XcmPauseEvent::<Runtime>::ExecutionSuspended.into());
+
+				assert_ok!(SafeMode::force_exit(RuntimeOrigin::root()));
+				assert!(!XcmExecutionSuspended::<Runtime>::get());
+				System::assert_has_event(XcmPauseEvent::<Runtime>::ExecutionResumed.into());
+				System::reset_events();
+			}
+		});
+	}
+
+	#[test]
+	fn xcm_is_resumed_when_safe_mode_expires() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(SafeMode::force_enter(RuntimeOrigin::root()));
+			let until = pallet_safe_mode::EnteredUntil::<Runtime>::get().unwrap();
+
+			System::set_block_number(until + 1);
+			SafeMode::on_initialize(until + 1);
+			assert!(!XcmExecutionSuspended::<Runtime>::get());
+
+			// entering again after the expiry suspends XCM again
+			assert_ok!(SafeMode::force_enter(RuntimeOrigin::root()));
+			assert!(XcmExecutionSuspended::<Runtime>::get());
+		});
+	}
+
+	#[test]
+	fn inbound_queues_are_not_serviced_in_safe_mode() {
+		new_test_ext().execute_with(|| {
+			let sibling = AggregateMessageOrigin::Sibling(2000.into());
+			let relay = AggregateMessageOrigin::Parent;
+
+			assert_ok!(SafeMode::force_enter(RuntimeOrigin::root()));
+			assert!(QueuePaused::is_paused(&sibling));
+			assert!(QueuePaused::is_paused(&relay));
+
+			// the queued messages are serviced again on exit
+			assert_ok!(SafeMode::force_exit(RuntimeOrigin::root()));
+			assert!(!QueuePaused::is_paused(&sibling));
+			assert!(!QueuePaused::is_paused(&relay));
+		});
+	}
+
+	#[test]
+	fn inbound_queues_are_paused_per_origin() {
+		new_test_ext().execute_with(|| {
+			let sibling = AggregateMessageOrigin::Sibling(2000.into());
+
+			assert_ok!(XcmPause::pause_origin(RuntimeOrigin::root(), sibling.clone()));
+			assert!(QueuePaused::is_paused(&sibling));
+			assert!(!QueuePaused::is_paused(&AggregateMessageOrigin::Sibling(2001.into())));
+			assert!(!QueuePaused::is_paused(&AggregateMessageOrigin::Parent));
+
+			assert_ok!(XcmPause::resume_origin(RuntimeOrigin::root(), sibling.clone()));
+			assert!(!QueuePaused::is_paused(&sibling));
+		});
+	}
+}
+
+mod call_filter {
+	use super::*;
+	use crate::{RuntimeCall, SafeMode, TxPause};
+	use frame_support::{assert_ok, traits::Contains};
+
+	type BaseCallFilter = <Runtime as frame_system::Config>::BaseCallFilter;
+
+	fn batch() -> RuntimeCall {
+		RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![] })
+	}
+
+	fn set_timestamp() -> RuntimeCall {
+		RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 0 })
+	}
+
+	#[test]
+	fn safe_mode_filters_all_but_whitelisted_calls() {
+		new_test_ext().execute_with(|| {
+			assert!(BaseCallFilter::contains(&batch()));
+
+			assert_ok!(SafeMode::force_enter(RuntimeOrigin::root()));
+			assert!(!BaseCallFilter::contains(&batch()));
+			// blocks can still be built
+			assert!(BaseCallFilter::contains(&set_timestamp()));
+
+			assert_ok!(SafeMode::force_exit(RuntimeOrigin::root()));
+			assert!(BaseCallFilter::contains(&batch()));
+		});
+	}
+
+	#[test]
+	fn paused_calls_are_filtered() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(TxPause::pause(
+				RuntimeOrigin::root(),
+				(b"Utility".to_vec().try_into().unwrap(), b"batch".to_vec().try_into().unwrap())
+			));
+			assert!(!BaseCallFilter::contains(&batch()));
+			assert!(BaseCallFilter::contains(&set_timestamp()));
+		});
+	}
+}
+
+mod migrations {
+	use super::*;
+	use crate::{AssetRegistry, AssetRegistryAccountFunding, FundAssetRegistryAccount};
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	#[test]
+	fn asset_registry_account_is_funded_from_the_treasury_once() {
+		new_test_ext().execute_with(|| {
+			let treasury = TreasuryAccount::get();
+			let registry = AssetRegistry::account_id();
+			let funding = AssetRegistryAccountFunding::get();
+			Balances::set_balance(&treasury, 10 * funding);
+			Balances::set_balance(&registry, funding / 4);
+
+			FundAssetRegistryAccount::on_runtime_upgrade();
+			assert_eq!(Balances::balance(&registry), funding);
+			assert_eq!(Balances::balance(&treasury), 10 * funding - funding * 3 / 4);
+
+			// later upgrades don't fund it again
+			Balances::set_balance(&registry, funding / 4);
+			FundAssetRegistryAccount::on_runtime_upgrade();
+			assert_eq!(Balances::balance(&registry), funding / 4);
+			assert_eq!(Balances::balance(&treasury), 10 * funding - funding * 3 / 4);
+		});
+	}
+}
+
+mod reserves {
+	use super::*;
+	use crate::xcm_config::{AssetHubLocation, Reserves};
+	use frame_support::traits::ContainsPair;
+
+	#[test]
+	fn roc_is_accepted_from_asset_hub() {
+		new_test_ext().execute_with(|| {
+			let roc: MultiAsset = (Parent, 1_000).into();
+
+			assert!(Reserves::contains(&roc, &AssetHubLocation::get()));
+			assert!(!Reserves::contains(&roc, &MultiLocation::new(1, X1(Parachain(2000)))));
+		});
+	}
+
+	#[test]
+	fn registered_assets_are_accepted_from_their_reserve() {
+		new_test_ext().execute_with(|| {
+			let asset: MultiAsset = (ASSET_LOCATION, 1_000).into();
+			assert!(!Reserves::contains(&asset, &AssetHubLocation::get()));
+
+			register_asset(ASSET_ID, ASSET_LOCATION);
+			assert!(Reserves::contains(&asset, &AssetHubLocation::get()));
+		});
+	}
+}
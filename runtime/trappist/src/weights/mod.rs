@@ -26,6 +26,7 @@ pub mod cumulus_pallet_xcmp_queue;
 pub mod extrinsic_weights;
 pub mod frame_system;
 pub mod pallet_asset_registry;
+pub mod pallet_asset_trap;
 pub mod pallet_assets;
 pub mod pallet_balances;
 pub mod pallet_collator_selection;
@@ -44,6 +45,7 @@ pub mod pallet_tx_pause;
 pub mod pallet_uniques;
 pub mod pallet_utility;
 pub mod pallet_withdraw_teleport;
+pub mod pallet_xcm_pause;
 pub mod trappist_runtime_benchmarks;
 pub mod xcm;
 
@@ -57,6 +59,10 @@ impl DropAssetsWeigher for TrappistDropAssetsWeigher {
 		trappist_runtime_benchmarks::WeightInfo::<Runtime>::drop_assets_native()
 	}
 
+	fn non_fungible() -> Weight {
+		trappist_runtime_benchmarks::WeightInfo::<Runtime>::drop_assets_non_fungible()
+	}
+
 	fn default() -> Weight {
 		trappist_runtime_benchmarks::WeightInfo::<Runtime>::drop_assets_default()
 	}
@@ -52,10 +52,16 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_asset_registry::WeightInfo for WeightInfo<T> {
 	/// Storage: `Assets::Asset` (r:1 w:0)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:1)
 	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:0 w:1)
 	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMetadata` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdMetadata` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::PendingRegistrations` (r:1 w:0)
+	/// Proof: `AssetRegistry::PendingRegistrations` (`max_values`: None, `max_size`: Some(774), added: 3249, mode: `MaxEncodedLen`)
 	fn register_reserve_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123`
@@ -63,19 +69,333 @@ impl<T: frame_system::Config> pallet_asset_registry::WeightInfo for WeightInfo<T
 		// Minimum execution time: 15_977_000 picoseconds.
 		Weight::from_parts(16_506_000, 0)
 			.saturating_add(Weight::from_parts(0, 4087))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:1)
 	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:0 w:1)
 	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMetadata` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdMetadata` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdTrust` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdTrust` (`max_values`: None, `max_size`: Some(623), added: 3098, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdUnitsPerSecond` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdUnitsPerSecond` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdStatus` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIdStatus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdFlowLimits` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdFlowLimits` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdFlow` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdFlow` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
 	fn unregister_reserve_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `107`
 		//  Estimated: `4087`
 		// Minimum execution time: 12_833_000 picoseconds.
 		Weight::from_parts(13_330_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:1 w:2)
+	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetAliasMultiLocationId` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetAliasMultiLocationId` (`max_values`: None, `max_size`: Some(626), added: 3101, mode: `MaxEncodedLen`)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn update_reserve_asset_location() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 19_019_000 picoseconds.
+		Weight::from_parts(19_812_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `AssetRegistry::AssetAliasMultiLocationId` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetAliasMultiLocationId` (`max_values`: None, `max_size`: Some(626), added: 3101, mode: `MaxEncodedLen`)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:0 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	fn remove_reserve_asset_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4091`
+		// Minimum execution time: 11_619_000 picoseconds.
+		Weight::from_parts(12_104_000, 0)
+			.saturating_add(Weight::from_parts(0, 4091))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdTrust` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdTrust` (`max_values`: None, `max_size`: Some(623), added: 3098, mode: `MaxEncodedLen`)
+	fn set_asset_trust() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 10_600_000 picoseconds.
+		Weight::from_parts(11_042_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::PendingRegistrations` (r:0 w:1)
+	/// Proof: `AssetRegistry::PendingRegistrations` (`max_values`: None, `max_size`: Some(774), added: 3249, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::PendingMultiLocationId` (r:1 w:1)
+	/// Proof: `AssetRegistry::PendingMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::NextDerivativeAssetId` (r:1 w:1)
+	/// Proof: `AssetRegistry::NextDerivativeAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn register_reserve_asset_with_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 33_713_000 picoseconds.
+		Weight::from_parts(35_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `AssetRegistry::PendingRegistrations` (r:1 w:1)
+	/// Proof: `AssetRegistry::PendingRegistrations` (`max_values`: None, `max_size`: Some(774), added: 3249, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMetadata` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdMetadata` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Metadata` (r:1 w:1)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::PendingMultiLocationId` (r:0 w:1)
+	/// Proof: `AssetRegistry::PendingMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	fn approve_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 27_321_000 picoseconds.
+		Weight::from_parts(28_460_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `AssetRegistry::PendingRegistrations` (r:1 w:1)
+	/// Proof: `AssetRegistry::PendingRegistrations` (`max_values`: None, `max_size`: Some(774), added: 3249, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::PendingMultiLocationId` (r:0 w:1)
+	/// Proof: `AssetRegistry::PendingMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	fn reject_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 38_180_000 picoseconds.
+		Weight::from_parts(39_771_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `AssetRegistry::PendingRegistrations` (r:1 w:1)
+	/// Proof: `AssetRegistry::PendingRegistrations` (`max_values`: None, `max_size`: Some(774), added: 3249, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::PendingMultiLocationId` (r:0 w:1)
+	/// Proof: `AssetRegistry::PendingMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	fn refund_reserve_asset_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `4239`
+		// Minimum execution time: 34_542_000 picoseconds.
+		Weight::from_parts(35_982_000, 0)
+			.saturating_add(Weight::from_parts(0, 4239))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::NextDerivativeAssetId` (r:1 w:1)
+	/// Proof: `AssetRegistry::NextDerivativeAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Metadata` (r:1 w:1)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMetadata` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdMetadata` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	fn create_and_register_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 44_051_000 picoseconds.
+		Weight::from_parts(45_887_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdUnitsPerSecond` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdUnitsPerSecond` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_asset_units_per_second() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_381_000 picoseconds.
+		Weight::from_parts(14_981_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdStatus` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdStatus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_asset_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_123_000 picoseconds.
+		Weight::from_parts(14_712_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdFlowLimits` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdFlowLimits` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdFlow` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdFlow` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_asset_flow_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 14_759_000 picoseconds.
+		Weight::from_parts(15_374_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `AssetRegistry::AssetIdStatus` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIdStatus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMetadata` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdMetadata` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdTrust` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdTrust` (`max_values`: None, `max_size`: Some(623), added: 3098, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdUnitsPerSecond` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdUnitsPerSecond` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdFlowLimits` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdFlowLimits` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdFlow` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdFlow` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	fn remove_deprecated_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 18_441_000 picoseconds.
+		Weight::from_parts(19_210_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `AssetRegistry::AssetIdMultiLocation` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdMetadata` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdMetadata` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdTrust` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdTrust` (`max_values`: None, `max_size`: Some(623), added: 3098, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdUnitsPerSecond` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdUnitsPerSecond` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdStatus` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdStatus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdFlowLimits` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdFlowLimits` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIdFlow` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetIdFlow` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: AssetRegistry AssetIdAliasMultiLocation (r:1 w:1)
+	/// Proof: AssetRegistry AssetIdAliasMultiLocation (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry AssetAliasMultiLocationId (r:0 w:1)
+	/// Proof: AssetRegistry AssetAliasMultiLocationId (max_values: None, max_size: Some(626), added: 3101, mode: MaxEncodedLen)
+	fn force_unregister_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 16_934_000 picoseconds.
+		Weight::from_parts(17_640_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::CollectionIdMultiLocation` (r:1 w:1)
+	/// Proof: `AssetRegistry::CollectionIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::CollectionMultiLocationId` (r:1 w:1)
+	/// Proof: `AssetRegistry::CollectionMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetMultiLocationId` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	fn register_reserve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 20_524_000 picoseconds.
+		Weight::from_parts(21_380_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `AssetRegistry::CollectionIdMultiLocation` (r:1 w:1)
+	/// Proof: `AssetRegistry::CollectionIdMultiLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::CollectionMultiLocationId` (r:0 w:1)
+	/// Proof: `AssetRegistry::CollectionMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	fn unregister_reserve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `4087`
+		// Minimum execution time: 15_283_000 picoseconds.
+		Weight::from_parts(15_920_000, 0)
 			.saturating_add(Weight::from_parts(0, 4087))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
@@ -0,0 +1,121 @@
+// This file is part of Trappist.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_asset_trap`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-02-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `runner-bn-ce5rx-project-647-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("trappist-dev")`, DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/trappist-node
+// benchmark
+// pallet
+// --chain=trappist-dev
+// --steps=50
+// --repeat=20
+// --no-storage-info
+// --no-median-slopes
+// --no-min-squares
+// --pallet=pallet_asset_trap
+// --extrinsic=*
+// --wasm-execution=compiled
+// --header=./templates/file_header.txt
+// --output=./runtime/trappist/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_asset_trap`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_asset_trap::WeightInfo for WeightInfo<T> {
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::ExpiryQueueTail` (r:1 w:1)
+	/// Proof: `AssetTrap::ExpiryQueueTail` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `AssetTrap::ExpiryQueue` (r:0 w:1)
+	/// Proof: `AssetTrap::ExpiryQueue` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn drop_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 21_847_000 picoseconds.
+		Weight::from_parts(22_405_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:64 w:64)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:128 w:128)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	/// The range of component `a` is `[1, 64]`.
+	fn sweep_trap(c: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312 + a * (291 ±0)`
+		//  Estimated: `3777 + a * (7903 ±0)`
+		// Minimum execution time: 61_452_000 picoseconds.
+		Weight::from_parts(18_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 3777))
+			// Standard Error: 2_113
+			.saturating_add(Weight::from_parts(1_106_324, 0).saturating_mul(c.into()))
+			// Standard Error: 3_301
+			.saturating_add(Weight::from_parts(43_517_861, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 7903).saturating_mul(a.into()))
+	}
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::Traps` (r:1 w:1)
+	/// Proof: `AssetTrap::Traps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetTrap::TrapsByOrigin` (r:0 w:1)
+	/// Proof: `AssetTrap::TrapsByOrigin` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_trapped() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `254`
+		//  Estimated: `3763`
+		// Minimum execution time: 46_682_000 picoseconds.
+		Weight::from_parts(47_931_000, 0)
+			.saturating_add(Weight::from_parts(0, 3763))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
@@ -62,14 +62,48 @@ impl<T: frame_system::Config> pallet_withdraw_teleport::WeightInfo for WeightInf
 	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
 	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:0 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
 	fn withdraw_and_teleport() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `143`
 		//  Estimated: `3608`
-		// Minimum execution time: 73_799_000 picoseconds.
-		Weight::from_parts(75_761_000, 0)
+		// Minimum execution time: 82_413_000 picoseconds.
+		Weight::from_parts(84_027_000, 0)
 			.saturating_add(Weight::from_parts(0, 3608))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:1 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn notify_teleport() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `278`
+		//  Estimated: `4678`
+		// Minimum execution time: 27_180_000 picoseconds.
+		Weight::from_parts(28_004_000, 0)
+			.saturating_add(Weight::from_parts(0, 4678))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `WithdrawTeleport::PendingTeleports` (r:1 w:1)
+	/// Proof: `WithdrawTeleport::PendingTeleports` (`max_values`: None, `max_size`: Some(1213), added: 3688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn expire_teleport() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `278`
+		//  Estimated: `4678`
+		// Minimum execution time: 26_730_000 picoseconds.
+		Weight::from_parts(27_552_000, 0)
+			.saturating_add(Weight::from_parts(0, 4678))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 }
@@ -74,6 +74,19 @@ impl<T: frame_system::Config> trappist_runtime_benchmarks::WeightInfo for Weight
 			.saturating_add(Weight::from_parts(0, 4087))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: `AssetRegistry::CollectionMultiLocationId` (r:1 w:0)
+	/// Proof: `AssetRegistry::CollectionMultiLocationId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	fn drop_assets_non_fungible() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `279`
+		//  Estimated: `4087`
+		// Minimum execution time: 9_012_000 picoseconds.
+		Weight::from_parts(9_289_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
 	fn drop_assets_default() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
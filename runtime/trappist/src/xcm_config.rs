@@ -18,7 +18,7 @@
 use cumulus_primitives_core::ParaId;
 use frame_support::{
 	match_types, parameter_types,
-	traits::{Contains, ContainsPair, EitherOfDiverse, Everything, Get, Nothing, PalletInfoAccess},
+	traits::{Contains, EitherOfDiverse, Everything, Nothing, PalletInfoAccess},
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
@@ -28,34 +28,39 @@ use parachains_common::AssetIdForTrustBackedAssets;
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::xcm_sender::{ExponentialPrice, NoPriceForMessageDelivery};
 use sp_core::ConstU32;
-use sp_std::{marker::PhantomData, vec::Vec};
+use sp_std::{vec, vec::Vec};
 use xcm::latest::{prelude::*, Fungibility::Fungible, MultiAsset, MultiLocation};
 use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, CurrencyAdapter,
 	DenyReserveTransferToRelayChain, DenyThenTry, DescribeAllTerminal, DescribeFamily,
 	EnsureXcmOrigin, FixedRateOfFungible, FungiblesAdapter, HashedDescription, IsConcrete,
-	MintLocation, NativeAsset, NoChecking, ParentAsSuperuser, ParentIsPreset, RelayChainAsNative,
-	SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
-	SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId,
-	UsingComponents, WeightInfoBounds, WithComputedOrigin,
+	MintLocation, NativeAsset, NoChecking, NonFungiblesAdapter, ParentAsSuperuser, ParentIsPreset,
+	RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
+	TrailingSetTopicAsId, UsingComponents, WeightInfoBounds, WithComputedOrigin,
 };
 use xcm_executor::{traits::JustTry, XcmExecutor};
 
 use hex_literal::hex;
-use xcm_primitives::{AsAssetMultiLocation, ConvertedRegisteredAssetId, TrappistDropAssets};
+use xcm_primitives::{
+	AsAssetMultiLocation, ConvertedRegisteredAssetId, ConvertedRegisteredCollectionId, DepositDust,
+	FlowLimitedTransactor, RegisteredReserves, RegisteredTeleporters, TrappistDropAssets,
+	UsingRegisteredUnitsPerSecond,
+};
 
 use crate::{
 	constants::fee::{default_fee_per_second, WeightToFee},
 	impls::ToAuthor,
 	weights,
 	weights::TrappistDropAssetsWeigher,
-	AggregateMessageOrigin, MessageQueue, TransactionByteFee, CENTS,
+	AggregateMessageOrigin, MessageQueue, TransactionByteFee, TreasuryAccount, CENTS,
 };
 
 use super::{
-	AccountId, AllPalletsWithSystem, AssetRegistry, Assets, Balance, Balances, ParachainInfo,
-	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, XcmpQueue,
+	AccountId, AllPalletsWithSystem, AssetRegistry, AssetTrap, Assets, Balance, Balances,
+	ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+	Uniques, XcmpQueue,
 };
 
 parameter_types! {
@@ -67,11 +72,16 @@ parameter_types! {
 	pub EthereumCurrencyLocation: MultiLocation = MultiLocation::new(2, X2(GlobalConsensus(Ethereum { chain_id: 11155111 }), AccountKey20{ network: None, key: hex!("c9F05326311bc2a55426761Bec20057685FB80f7") }));
 	pub AssetsPalletLocation: MultiLocation =
 		PalletInstance(<Assets as PalletInfoAccess>::index() as u8).into();
+	pub UniquesPalletLocation: MultiLocation =
+		PalletInstance(<Uniques as PalletInfoAccess>::index() as u8).into();
 	// Be mindful with incoming teleports if you implement this
 	pub CheckAccount: (AccountId, MintLocation) = (PolkadotXcm::check_account(), MintLocation::Local);
 	pub PlaceholderAccount: AccountId = PolkadotXcm::check_account();
 	pub const ExecutiveBody: BodyId = BodyId::Executive;
 	pub const MaxAssetsIntoHolding: u32 = 64;
+	// Dust left in holding accumulates in the Treasury (pallet instance 61)
+	pub TreasuryAccountLocation: MultiLocation =
+		AccountId32 { network: None, id: TreasuryAccount::get().into() }.into();
 	pub UniversalLocation: InteriorMultiLocation = (
 		GlobalConsensus(NetworkId::Rococo),
 		Parachain(ParachainInfo::parachain_id().into()),
@@ -159,6 +169,10 @@ pub type ReservedFungiblesTransactor = FungiblesAdapter<
 		Balance,
 		AsAssetMultiLocation<AssetIdForTrustBackedAssets, AssetRegistry>,
 		JustTry,
+		// Scale amounts when the reserve uses different decimals than the derivative
+		AssetRegistry,
+		// Refuse assets frozen or quarantined in the registry
+		AssetRegistry,
 	>,
 	// Convert an XCM MultiLocation into a local account id:
 	LocationToAccountId,
@@ -171,12 +185,59 @@ pub type ReservedFungiblesTransactor = FungiblesAdapter<
 	PlaceholderAccount,
 >;
 
+/// Means for transacting derivatives of non-fungible assets.
+/// Items of collections registered in AssetRegistry are minted in, and burnt from, the local
+/// collection the remote one is mapped to.
+pub type ReservedNonFungiblesTransactor = NonFungiblesAdapter<
+	// Use this non-fungibles implementation:
+	Uniques,
+	// Use this collection when the asset belongs to a collection registered in AssetRegistry
+	ConvertedRegisteredCollectionId<u32, u32, AssetRegistry, JustTry>,
+	// Convert an XCM MultiLocation into a local account id:
+	LocationToAccountId,
+	// Our chain's account ID type (we can't get away without mentioning it explicitly):
+	AccountId,
+	// We don't track any teleports of `Uniques`.
+	NoChecking,
+	// We don't track any teleports of `Uniques`, but a placeholder account is provided due to
+	// trait bounds.
+	PlaceholderAccount,
+>;
+
+/// Items that are trapped when left in holding: those of local `Uniques` collections and of
+/// collections registered in AssetRegistry.
+pub type TrappableNonFungibles = (
+	assets_common::UniquesConvertedConcreteId<UniquesPalletLocation>,
+	ConvertedRegisteredCollectionId<u32, u32, AssetRegistry, JustTry>,
+);
+
 /// Means for transacting assets on this chain.
 pub type AssetTransactors = (
+	LocalAssetTransactor,
+	BridgedLocalAssetTransactor,
+	// Deposits and withdrawals of registered assets are subject to their flow limits
+	FlowLimitedTransactor<ReservedFungiblesTransactor, AssetRegistry>,
+	LocalFungiblesTransactor,
+	ReservedNonFungiblesTransactor,
+);
+
+/// Means for depositing the dust left in holding, bypassing the flow limits of registered assets.
+pub type DustTransactors = (
+	LocalAssetTransactor,
+	BridgedLocalAssetTransactor,
+	ReservedFungiblesTransactor,
+	LocalFungiblesTransactor,
+);
+
+/// Means for depositing the assets of swept and claimed traps, bypassing the flow limits of
+/// registered assets: recovering trapped assets can't quarantine them, nor be refused for
+/// deprecated ones.
+pub type TrappedAssetTransactors = (
 	LocalAssetTransactor,
 	BridgedLocalAssetTransactor,
 	ReservedFungiblesTransactor,
 	LocalFungiblesTransactor,
+	ReservedNonFungiblesTransactor,
 );
 
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
@@ -232,6 +293,11 @@ match_types! {
 		MultiLocation { parents: 1, interior: X1(_) }
 	};
 }
+match_types! {
+	pub type SiblingParachains: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 1, interior: X1(Parachain(_)) }
+	};
+}
 match_types! {
 	pub type AssetHub: impl Contains<MultiLocation> = {
 		MultiLocation { parents: 1, interior: X1(Parachain(1000)) }
@@ -277,20 +343,28 @@ parameter_types! {
 	);
 	/// Roc = 7 RUSD
 	pub RocPerSecond: (xcm::v3::AssetId, u128,u128) = (MultiLocation::new(1,Here).into(), default_fee_per_second() * 70, 0u128);
+	/// Fees charged on destination by `WithdrawTeleport` messages, approximated by the rate at
+	/// which Trappist charges ROC. Only used to quote fees in dry-runs: the fee asset actually
+	/// withdrawn on destination is set by the caller, and what is not spent is refunded there.
+	pub DestinationFeeRates: Vec<(MultiLocation, AssetId, u128, u128)> = {
+		let (roc, units_per_second, units_per_mb) = RocPerSecond::get();
+		vec![
+			(RelayLocation::get(), roc, units_per_second, units_per_mb),
+			(AssetHubLocation::get(), roc, units_per_second, units_per_mb),
+		]
+	};
 }
 
 parameter_types! {
 	pub const TrappistNative: MultiAssetFilter = Wild(AllOf { fun: WildFungible, id: Concrete(MultiLocation::here()) });
 	pub AssetHubTrustedTeleporter: (MultiAssetFilter, MultiLocation) = (TrappistNative::get(), AssetHubLocation::get());
-}
-
-pub struct ReserveAssetsFrom<T>(PhantomData<T>);
-impl<T: Get<MultiLocation>> ContainsPair<MultiAsset, MultiLocation> for ReserveAssetsFrom<T> {
-	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
-		let prefix = T::get();
-		log::trace!(target: "xcm::AssetsFrom", "prefix: {:?}, origin: {:?}, asset: {:?}", prefix, origin, asset);
-		&prefix == origin
-	}
+	// The bridged Ethereum currency is backed by `Balances` rather than the registry.
+	pub EthereumCurrency: MultiAssetFilter = Wild(AllOf { fun: WildFungible, id: Concrete(EthereumCurrencyLocation::get()) });
+	pub EthereumCurrencyFromAssetHub: (MultiAssetFilter, MultiLocation) = (EthereumCurrency::get(), AssetHubLocation::get());
+	// ROC is not registered either: it keeps arriving from Asset Hub, as `RocPerSecond` and
+	// `DestinationFeeRates` expect.
+	pub Roc: MultiAssetFilter = Wild(AllOf { fun: WildFungible, id: Concrete(RelayLocation::get()) });
+	pub RocFromAssetHub: (MultiAssetFilter, MultiLocation) = (Roc::get(), AssetHubLocation::get());
 }
 
 pub struct OnlyTeleportNative;
@@ -318,12 +392,21 @@ pub type Traders = (
 	FixedRateOfFungible<RUsdPerSecond, ()>,
 	// Roc
 	FixedRateOfFungible<RocPerSecond, ()>,
+	// Registered assets priced on-chain
+	UsingRegisteredUnitsPerSecond<AssetRegistry, ()>,
 	// Everything else
 	UsingComponents<WeightToFee, SelfReserve, AccountId, Balances, ToAuthor<Runtime>>,
 );
 
-pub type Reserves = (NativeAsset, ReserveAssetsFrom<AssetHubLocation>);
-pub type TrustedTeleporters = (xcm_builder::Case<AssetHubTrustedTeleporter>,);
+/// Registered assets are trusted according to the trust mode stored in the registry.
+pub type Reserves = (
+	NativeAsset,
+	RegisteredReserves<AssetRegistry>,
+	xcm_builder::Case<EthereumCurrencyFromAssetHub>,
+	xcm_builder::Case<RocFromAssetHub>,
+);
+pub type TrustedTeleporters =
+	(xcm_builder::Case<AssetHubTrustedTeleporter>, RegisteredTeleporters<AssetRegistry>);
 
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
@@ -348,13 +431,17 @@ impl xcm_executor::Config for XcmConfig {
 		AssetRegistry,
 		Assets,
 		Balances,
-		PolkadotXcm,
+		TrappableNonFungibles,
+		Uniques,
+		AssetTrap,
 		AccountId,
 		TrappistDropAssetsWeigher,
+		DepositDust<DustTransactors, TreasuryAccountLocation, AssetRegistry>,
+		AssetRegistry,
 	>;
 	type AssetLocker = ();
 	type AssetExchanger = ();
-	type AssetClaims = PolkadotXcm;
+	type AssetClaims = AssetTrap;
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
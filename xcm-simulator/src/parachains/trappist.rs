@@ -18,7 +18,7 @@
 
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{AsEnsureOriginWithArg, EitherOfDiverse, Everything, Nothing},
+	traits::{tokens::nonfungibles, AsEnsureOriginWithArg, EitherOfDiverse, Everything, Nothing},
 	weights::constants::RocksDbWeight,
 };
 use frame_system::EnsureRoot;
@@ -48,7 +48,7 @@ use xcm_builder::{
 	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, UsingComponents,
 };
 use xcm_executor::{Config, XcmExecutor};
-use xcm_primitives::TrappistDropAssets;
+use xcm_primitives::{TrappistDropAssets, UsingRegisteredUnitsPerSecond};
 
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = Everything;
@@ -105,16 +105,50 @@ impl pallet_assets::Config for Runtime {
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
-	type CallbackHandle = ();
+	type CallbackHandle = AssetRegistry;
+}
+
+parameter_types! {
+	pub const AssetRegistryPalletId: frame_support::PalletId =
+		frame_support::PalletId(*b"py/asreg");
+	pub const DerivativeAssetIdRange: (AssetId, AssetId) = (1_000_000_000, AssetId::MAX);
 }
 
 impl pallet_asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type ReserveAssetModifierOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ReserveAssetModifierOrigin =
+		frame_system::EnsureRootWithSuccess<Self::AccountId, frame_support::traits::GetDefault>;
+	type AssetId = AssetId;
 	type Assets = Assets;
+	type DerivativeAssetIdRange = DerivativeAssetIdRange;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type RegistrationDeposit = ConstU128<{ 100 * UNITS }>;
+	type PalletId = AssetRegistryPalletId;
+	type StringLimit = ConstU32<50>;
+	type AssetLocationFilter = (
+		pallet_asset_registry::StandardAssetLocations,
+		pallet_asset_registry::BridgedAssetLocations<pallet_asset_registry::StandardAssetLocations>,
+	);
+	type FlowLimitWindow = ConstU32<600>;
+	type CollectionId = u32;
+	type Collections = NoCollections;
 	type WeightInfo = pallet_asset_registry::weights::SubstrateWeight<Runtime>;
 }
 
+/// The simulated parachain holds no collections, non-fungible transfers are covered by the
+/// runtime tests.
+pub struct NoCollections;
+
+impl nonfungibles::Inspect<AccountId> for NoCollections {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_collection: &u32, _item: &u32) -> Option<AccountId> {
+		None
+	}
+}
+
 impl pallet_balances::Config for Runtime {
 	type Balance = Balance;
 	type DustRemoval = ();
@@ -203,6 +237,7 @@ impl Config for XcmConfig {
 	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
 	type Trader = (
 		FixedRateOfFungible<XUsdPerSecond, ()>,
+		UsingRegisteredUnitsPerSecond<AssetRegistry, ()>,
 		UsingComponents<WeightToFee, SelfReserve, AccountId, Balances, DealWithFees<Runtime>>,
 	);
 	type ResponseHandler = PolkadotXcm;
@@ -229,6 +264,6 @@ construct_runtime!(
 		CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin} = 32,
 		Sudo: pallet_sudo = 40,
 		Assets: pallet_assets = 43,
-		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Event<T>} = 101,
+		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Event<T>, Config<T>, HoldReason} = 101,
 	}
 );
@@ -114,6 +114,7 @@ fn register_reserve_asset_on_trappist(
 				),
 			)
 				.into(),
+			metadata: None,
 		})),
 	)
 }